@@ -1,12 +1,21 @@
 use anyhow::Result;
 use cli_clipboard::ClipboardContext;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::sync::OnceCell;
 use tokio::{sync::mpsc, task::JoinHandle};
 
+use crate::application::params::ParameterPrompt;
+use crate::application::profiles::ProfilePicker;
+use crate::application::query_execution::timeout_message;
 use crate::application::state::{AppMessage, AppMode, StartupResult};
+use crate::channel_monitor::ChannelMonitor;
 use crate::database::DatabaseManager;
-use crate::table_viewer::TableViewer;
+use crate::database::timeout::timeout_cause;
+use crate::query_plan::PlanViewer;
+use crate::schema_browser::SchemaBrowser;
+use crate::table_viewer::{ForeignKeyNavigation, TableViewer};
 use crate::ui::UI;
 use crate::{
     config::Config,
@@ -16,19 +25,22 @@ use crate::{
 
 pub struct App {
     pub config: Config,
-    pub db_manager: OnceCell<DatabaseManager>,
+    /// Shared with spawned query tasks so they all use the same pool and transaction.
+    pub db_manager: Arc<OnceCell<DatabaseManager>>,
     pub current_mode: AppMode,
     pub previous_mode: Option<AppMode>,
     pub current_file: Option<PathBuf>,
     pub file_content: String,
     pub matrix_animation: Option<MatrixAnimation>,
     pub table_viewer: Option<TableViewer>,
-    pub foreign_key_viewer: Option<TableViewer>,
+    pub fk_navigation: ForeignKeyNavigation,
     pub search_input: String,
     pub search_cursor_position: u16,
     pub movement_multiplier: Option<usize>,
     pub ui: UI,
     pub should_quit: bool,
+    /// Set while the `COMMIT` or `ROLLBACK` chosen on quitting is still running.
+    pub quit_pending: bool,
     pub cursor_line: usize,
     pub scroll_offset: usize,
     pub yank_sequence: String,
@@ -38,7 +50,23 @@ pub struct App {
     pub pending_query: Option<String>,
     pub file_loading_complete: bool,
     pub startup_task: Option<JoinHandle<Result<StartupResult>>>,
+    pub query_task: Option<JoinHandle<()>>,
+    /// Summary of the last statement that reported an outcome, e.g. `UPDATE 42 (12 ms)`.
+    pub last_outcome: Option<String>,
     pub cell_info: Option<crate::table_viewer::CellInfo>,
+    pub references_view: Option<crate::table_viewer::ReferencesView>,
+    pub schema_browser: Option<SchemaBrowser>,
+    pub is_loading_schema: bool,
+    /// Set from `connect_database` until its `Connected` result arrives.
+    pub is_connecting: bool,
+    pub parameter_prompt: Option<ParameterPrompt>,
+    /// Last value entered for each placeholder name, offered again the next time it is asked for.
+    pub parameter_history: HashMap<String, String>,
+    pub profile_picker: Option<ProfilePicker>,
+    pub plan_viewer: Option<PlanViewer>,
+    pub channel_monitor: Option<ChannelMonitor>,
+    /// Forwards notifications to the monitor until aborted.
+    pub listener_task: Option<JoinHandle<()>>,
     pub query_parser: QueryParser,
     pub query_blocks: Vec<QueryBlock>,
     pub app_tx: mpsc::Sender<AppMessage>,
@@ -56,6 +84,10 @@ impl App {
             self.execute_query_internal(query).await?;
         } else {
             self.current_mode = AppMode::FileView;
+            // Connecting now rather than on the first query reports a failing init_sql early.
+            if !self.config.database.init_sql.is_empty() {
+                self.connect_database();
+            }
         }
 
         self.startup_complete = true;
@@ -75,6 +107,7 @@ impl App {
                 table_viewer: None,
                 success_message: None,
                 error_message: None,
+                warning_messages: Vec::new(),
             };
 
             let query_parser = QueryParser::new();
@@ -106,6 +139,7 @@ impl App {
                 })
                 .await
                 .map_err(|e| anyhow::anyhow!("Database manager initialization failed: {}", e))?;
+            result.warning_messages = db_manager.foreign_key_warnings().to_vec();
 
             if let Some(query) = &pending_query &&
             !query.trim().is_empty() {
@@ -123,9 +157,12 @@ impl App {
                             }
                         }
                     }
-                    Err(e) => {
-                        result.error_message = Some(format!("Query execution failed: {}", e));
-                    }
+                    Err(e) => match timeout_cause(&e) {
+                        Some(cause) => result.warning_messages.push(timeout_message(cause, &e)),
+                        None => {
+                            result.error_message = Some(format!("Query execution failed: {}", e));
+                        }
+                    },
                 }
             }
 
@@ -0,0 +1,73 @@
+use anyhow::Result;
+
+use crate::{
+    application::{
+        app::App,
+        state::{AppMessage, AppMode},
+    },
+    database::DatabaseManager,
+    schema_browser::PREVIEW_ROW_LIMIT,
+    ui::ToastType,
+};
+
+impl App {
+    /// Shows the schema browser, loading the catalog the first time it is opened.
+    pub fn open_schema_browser(&mut self) {
+        self.current_mode = AppMode::SchemaBrowser;
+        if self.schema_browser.is_none() {
+            self.load_schema();
+        }
+    }
+
+    /// Fetches the catalog in the background; the result arrives as `SchemaLoaded`.
+    pub fn load_schema(&mut self) {
+        if self.is_loading_schema {
+            return;
+        }
+        self.is_loading_schema = true;
+
+        let config = self.config.clone();
+        let app_tx = self.app_tx.clone();
+        let db_man_clone = self.db_manager.clone();
+
+        tokio::spawn(async move {
+            let result = match db_man_clone
+                .get_or_try_init(|| async { DatabaseManager::new(&config).await })
+                .await
+            {
+                Ok(db_manager) => db_manager
+                    .introspect_schema()
+                    .await
+                    .map_err(|e| format!("Failed to load schema: {}", e)),
+                Err(e) => Err(format!("Database manager initialization failed: {}", e)),
+            };
+            let _ = app_tx.send(AppMessage::SchemaLoaded(result)).await;
+        });
+    }
+
+    pub async fn preview_selected_table(&mut self) -> Result<()> {
+        if self.is_querying {
+            self.ui
+                .add_toast("A query is already running".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        let Some(table) = self
+            .schema_browser
+            .as_ref()
+            .and_then(|browser| browser.selected_table())
+        else {
+            return Ok(());
+        };
+        let db_manager = self
+            .db_manager
+            .get_or_try_init(|| async { DatabaseManager::new(&self.config).await })
+            .await?;
+
+        let query = db_manager.preview_query(&table.schema, &table.name, PREVIEW_ROW_LIMIT);
+        self.execute_query(&query)
+    }
+
+    pub fn close_schema_browser(&mut self) {
+        self.current_mode = AppMode::FileView;
+    }
+}
@@ -0,0 +1,98 @@
+use futures::StreamExt;
+
+use crate::{
+    application::{
+        app::App,
+        state::{AppMessage, AppMode},
+    },
+    channel_monitor::ChannelMonitor,
+    database::DatabaseManager,
+    ui::ToastType,
+};
+
+impl App {
+    /// Shows the channel monitor, asking which channels to listen on if it is not listening.
+    pub fn open_channel_monitor(&mut self) {
+        if self.channel_monitor.is_none() {
+            self.channel_monitor = Some(ChannelMonitor::new());
+        }
+        self.current_mode = AppMode::ChannelMonitor;
+    }
+
+    /// Leaves the monitor, which keeps collecting notifications in the background.
+    pub fn close_channel_monitor(&mut self) {
+        self.current_mode = AppMode::FileView;
+        if let Some(monitor) = &self.channel_monitor
+            && monitor.is_listening()
+        {
+            self.ui.add_toast(
+                format!(
+                    "Still listening on {}; L to return",
+                    monitor.channels.join(", ")
+                ),
+                ToastType::Info,
+            );
+        }
+    }
+
+    /// Replaces any running listener with one subscribed to `channels`. The subscription
+    /// arrives as `Listening` and each notification after it as `ChannelNotification`.
+    pub fn listen_to_channels(&mut self, channels: Vec<String>) {
+        if let Some(task) = self.listener_task.take() {
+            task.abort();
+        }
+
+        let config = self.config.clone();
+        let app_tx = self.app_tx.clone();
+        let db_man_clone = self.db_manager.clone();
+
+        self.listener_task = Some(tokio::spawn(async move {
+            let stream = match db_man_clone
+                .get_or_try_init(|| async { DatabaseManager::new(&config).await })
+                .await
+            {
+                Ok(db_manager) => db_manager.listen(&channels).await,
+                Err(e) => Err(anyhow::anyhow!(
+                    "Database manager initialization failed: {}",
+                    e
+                )),
+            };
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    let message = format!("LISTEN failed: {}", e);
+                    let _ = app_tx.send(AppMessage::Listening(Err(message))).await;
+                    return;
+                }
+            };
+            if app_tx
+                .send(AppMessage::Listening(Ok(channels)))
+                .await
+                .is_err()
+            {
+                return;
+            }
+            while let Some(notification) = stream.next().await {
+                let message = match notification {
+                    Ok(notification) => AppMessage::ChannelNotification(notification),
+                    Err(e) => AppMessage::ListenerStopped(format!("Listener stopped: {}", e)),
+                };
+                let stopped = matches!(message, AppMessage::ListenerStopped(_));
+                if app_tx.send(message).await.is_err() || stopped {
+                    return;
+                }
+            }
+        }));
+    }
+
+    /// Unsubscribes and closes the monitor along with the notifications it collected.
+    pub fn stop_listening(&mut self) {
+        if let Some(task) = self.listener_task.take() {
+            task.abort();
+        }
+        self.channel_monitor = None;
+        if self.current_mode == AppMode::ChannelMonitor {
+            self.current_mode = AppMode::FileView;
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::database::Value;
 use crate::ui::{ToastType, UI};
 use anyhow::Result;
 use cli_clipboard::{ClipboardContext, ClipboardProvider};
@@ -27,7 +28,7 @@ impl ClipboardManager {
         }
     }
 
-    pub fn copy_row(&mut self, headers: &[String], values: &[String], ui: &mut UI) {
+    pub fn copy_row(&mut self, headers: &[String], values: &[Value], ui: &mut UI) {
         let formatted_row = headers
             .iter()
             .zip(values.iter())
@@ -15,6 +15,8 @@ use crate::{
     },
     config::MatrixConfig,
     matrix::MatrixAnimation,
+    query_plan::PlanViewer,
+    schema_browser::SchemaBrowser,
     ui::ToastType,
 };
 
@@ -95,27 +97,115 @@ impl App {
                     self.is_querying = false; // Clear loading state
 
                     match result {
-                        Ok(viewer) => {
-                            self.table_viewer = Some(viewer);
-
-                            if self.matrix_animation.is_none() {
-                                self.current_mode = AppMode::TableViewer;
-                            }
+                        Ok(viewer) => self.show_query_viewer(*viewer),
+                        Err(e) => {
+                            self.ui.add_toast(e, ToastType::Error);
+                            self.current_mode = AppMode::FileView;
+                            self.matrix_animation = None;
+                        }
+                    }
+                }
+                AppMessage::ServerNotices(notices) => self.show_server_notices(&notices),
+                AppMessage::QueryTimedOut(message) => {
+                    self.is_querying = false;
+                    self.ui.add_toast(message, ToastType::Warning);
+                    self.current_mode = AppMode::FileView;
+                    self.matrix_animation = None;
+                }
+                AppMessage::ServerCancelFailed(e) => self.ui.add_toast(
+                    format!("Query cancelled locally, server cancel failed: {}", e),
+                    ToastType::Warning,
+                ),
+                AppMessage::MoreRows(result) => {
+                    self.is_querying = false;
 
-                            if self.startup_complete || !self.config.matrix.enabled {
+                    match result {
+                        Ok(query_result) => {
+                            if let Some(viewer) = &mut self.table_viewer {
+                                let added = query_result.rows.len();
+                                viewer.append_rows(query_result);
                                 self.ui.add_toast(
-                                    "Query executed successfully".to_string(),
+                                    format!("Loaded {} more rows", added),
                                     ToastType::Success,
                                 );
                             }
                         }
+                        Err(e) => self.ui.add_toast(e, ToastType::Error),
+                    }
+                }
+                AppMessage::SchemaLoaded(result) => {
+                    self.is_loading_schema = false;
+
+                    match result {
+                        Ok(schemas) => {
+                            self.schema_browser = Some(SchemaBrowser::new(schemas));
+                        }
                         Err(e) => {
                             self.ui.add_toast(e, ToastType::Error);
-                            self.current_mode = AppMode::FileView;
-                            self.matrix_animation = None;
+                            if self.schema_browser.is_none()
+                                && self.current_mode == AppMode::SchemaBrowser
+                            {
+                                self.close_schema_browser();
+                            }
                         }
                     }
                 }
+                AppMessage::PlanLoaded(result) => {
+                    self.is_querying = false;
+
+                    match result {
+                        Ok(plan) => {
+                            self.plan_viewer = Some(PlanViewer::new(*plan));
+                            self.current_mode = AppMode::PlanViewer;
+                        }
+                        Err(e) => self.ui.add_toast(e, ToastType::Error),
+                    }
+                }
+                AppMessage::Listening(result) => match result {
+                    Ok(channels) => {
+                        self.ui.add_toast(
+                            format!("Listening on {}", channels.join(", ")),
+                            ToastType::Success,
+                        );
+                        if let Some(monitor) = &mut self.channel_monitor {
+                            monitor.channels = channels;
+                        }
+                    }
+                    Err(e) => {
+                        self.ui.add_toast(e, ToastType::Error);
+                        // Ask again, unless an earlier subscription is still running.
+                        if let Some(monitor) = &mut self.channel_monitor
+                            && !monitor.is_listening()
+                        {
+                            monitor.input = Some(String::new());
+                        }
+                    }
+                },
+                AppMessage::ChannelNotification(notification) => {
+                    if let Some(monitor) = &mut self.channel_monitor {
+                        monitor.push(notification);
+                    }
+                }
+                AppMessage::ListenerStopped(e) => {
+                    self.ui.add_toast(e, ToastType::Error);
+                    if let Some(monitor) = &mut self.channel_monitor {
+                        monitor.channels.clear();
+                    }
+                }
+                AppMessage::Connected(result) => {
+                    self.is_connecting = false;
+                    match result {
+                        Ok((name, warnings)) => {
+                            self.ui
+                                .add_toast(format!("Connected to {}", name), ToastType::Success);
+                            for warning in warnings {
+                                self.ui.add_toast(warning, ToastType::Warning);
+                            }
+                        }
+                        Err(e) => self.ui.add_toast(e, ToastType::Error),
+                    }
+                }
+                AppMessage::TransactionEnded(result) => self.finish_quit(result),
             }
         }
 
@@ -191,6 +281,9 @@ impl App {
         if let Some(error_msg) = result.error_message {
             self.ui.add_toast(error_msg, ToastType::Error);
         }
+        for warning in result.warning_messages {
+            self.ui.add_toast(warning, ToastType::Warning);
+        }
 
         Ok(())
     }
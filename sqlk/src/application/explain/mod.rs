@@ -0,0 +1,88 @@
+use anyhow::Result;
+
+use crate::{
+    application::{
+        app::App,
+        query_execution::timeout_message,
+        state::{AppMessage, AppMode},
+    },
+    database::{DatabaseManager, QueryParam, timeout::timeout_cause},
+    query_plan::{ExplainMode, QueryPlan},
+    ui::ToastType,
+};
+
+impl App {
+    /// Explains the query under the cursor, asking for placeholder values first.
+    pub fn explain_current_query(&mut self, mode: ExplainMode) -> Result<()> {
+        if self.is_querying {
+            self.ui
+                .add_toast("A query is already running".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        let Some(query_block) = self
+            .get_current_query_block()
+            .filter(|block| !block.text.trim().is_empty())
+        else {
+            self.ui.add_toast(
+                "No query found at current cursor position".to_string(),
+                ToastType::Error,
+            );
+            return Ok(());
+        };
+        let query = query_block.text.clone();
+        if query_block.placeholders.is_empty() {
+            self.explain_query(&query, Vec::new(), mode)
+        } else {
+            let names = query_block.placeholders.clone();
+            self.open_parameter_prompt(query, names, Some(mode));
+            Ok(())
+        }
+    }
+
+    /// Fetches the plan in the background; it arrives as `PlanLoaded`.
+    pub fn explain_query(
+        &mut self,
+        query: &str,
+        params: Vec<QueryParam>,
+        mode: ExplainMode,
+    ) -> Result<()> {
+        self.is_querying = true;
+
+        let query_string = query.to_string();
+        let config = self.config.clone();
+        let app_tx = self.app_tx.clone();
+        let db_man_clone = self.db_manager.clone();
+
+        self.query_task = Some(tokio::spawn(async move {
+            let message = match db_man_clone
+                .get_or_try_init(|| async { DatabaseManager::new(&config).await })
+                .await
+            {
+                Ok(db_manager) => match db_manager.explain(&query_string, &params, mode).await {
+                    Ok(json) => AppMessage::PlanLoaded(
+                        QueryPlan::from_json(&json, mode, query_string)
+                            .map(Box::new)
+                            .map_err(|e| format!("Failed to read plan: {}", e)),
+                    ),
+                    Err(e) => match timeout_cause(&e) {
+                        Some(cause) => AppMessage::QueryTimedOut(timeout_message(cause, &e)),
+                        None => {
+                            AppMessage::PlanLoaded(Err(format!("{} failed: {}", mode.label(), e)))
+                        }
+                    },
+                },
+                Err(e) => AppMessage::PlanLoaded(Err(format!(
+                    "Database manager initialization failed: {}",
+                    e
+                ))),
+            };
+            let _ = app_tx.send(message).await;
+        }));
+
+        Ok(())
+    }
+
+    pub fn close_plan_viewer(&mut self) {
+        self.current_mode = AppMode::FileView;
+    }
+}
@@ -1,16 +1,26 @@
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::application::{app::App, state::AppMode};
 
 impl App {
     pub async fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
+        if self.is_querying
+            && key.code == KeyCode::Char('c')
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.cancel_query();
+            return Ok(());
+        }
+
         match self.current_mode {
             AppMode::FileView => self.handle_file_view_keys(key).await?,
             AppMode::TableViewer => self.handle_table_viewer_keys(key).await?,
             AppMode::ForeignKeyView => self.handle_foreign_key_viewer_keys(key).await?,
             AppMode::Searching => self.handle_searching_keys(key).await?,
             AppMode::CellInfoView => self.handle_cell_info_keys(key).await?,
+            AppMode::ReferencesView => self.handle_references_keys(key).await?,
+            AppMode::SchemaBrowser => self.handle_schema_browser_keys(key).await?,
             AppMode::MatrixLoading => {
                 if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
                     self.should_quit = true;
@@ -22,6 +32,11 @@ impl App {
                 }
             }
             AppMode::Help => self.handle_help_keys(key).await?,
+            AppMode::ParameterPrompt => self.handle_parameter_prompt_keys(key).await?,
+            AppMode::ConfirmQuit => self.handle_confirm_quit_keys(key).await?,
+            AppMode::ProfilePicker => self.handle_profile_picker_keys(key).await?,
+            AppMode::PlanViewer => self.handle_plan_viewer_keys(key).await?,
+            AppMode::ChannelMonitor => self.handle_channel_monitor_keys(key).await?,
         }
         Ok(())
     }
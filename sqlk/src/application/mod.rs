@@ -1,8 +1,15 @@
 pub mod app;
+pub mod browser;
+pub mod channels;
 pub mod clipboard;
 pub mod events;
+pub mod explain;
 pub mod file_operations;
 pub mod input;
 pub mod modes;
+pub mod navigation;
+pub mod params;
+pub mod profiles;
 pub mod query_execution;
 pub mod state;
+pub mod transaction;
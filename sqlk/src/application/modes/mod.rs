@@ -1,13 +1,15 @@
 use anyhow::Result;
 use cli_clipboard::ClipboardProvider;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::{
     application::{app::App, state::AppMode},
+    query_plan::ExplainMode,
     ui::ToastType,
 };
 
 impl App {
+    #[allow(clippy::collapsible_match)]
     pub async fn handle_searching_keys(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Left => {
@@ -72,7 +74,7 @@ impl App {
 
                 match sequence.as_str() {
                     "yy" => {
-                        if let Some(viewer) = &self.foreign_key_viewer {
+                        if let Some(viewer) = self.fk_navigation.current() {
                             if let Some((headers, row_values)) =
                                 viewer.get_current_row_with_headers()
                             {
@@ -104,7 +106,7 @@ impl App {
                         }
                     }
                     "yiw" => {
-                        if let Some(viewer) = &self.foreign_key_viewer {
+                        if let Some(viewer) = self.fk_navigation.current() {
                             if let Some(cell_value) = viewer.get_current_cell_value() {
                                 match self.clipboard.set_contents(cell_value.to_string()) {
                                     Ok(_) => {
@@ -136,16 +138,17 @@ impl App {
             KeyCode::Char('y') => {
                 self.yank_sequence.push('y');
             }
-            KeyCode::Char('q') | KeyCode::Esc => {
-                self.foreign_key_viewer = None;
-                self.current_mode = AppMode::TableViewer;
-            }
+            KeyCode::Char('q') | KeyCode::Esc => self.close_foreign_key_view(),
+            KeyCode::Char('[') | KeyCode::Backspace => self.navigate_back(),
+            KeyCode::Char(']') => self.navigate_forward(),
+            KeyCode::Char('F') => self.follow_foreign_key().await?,
+            KeyCode::Char('R') => self.open_references_view().await?,
             KeyCode::Char('?') => {
                 self.previous_mode = Some(self.current_mode.clone());
                 self.current_mode = AppMode::Help;
             }
             _ => {
-                if let Some(viewer) = &mut self.foreign_key_viewer {
+                if let Some(viewer) = self.fk_navigation.current_mut() {
                     viewer.handle_key(key).await?;
                 }
             }
@@ -153,16 +156,24 @@ impl App {
         Ok(())
     }
 
-    pub async fn handle_file_view_keys(&mut self, key: KeyEvent) -> Result<()> {
-        if let KeyCode::Char(c) = key.code &&
-            c.is_ascii_digit() {
-                let digit = c.to_digit(10).unwrap() as usize;
-                let current_multiplier = self.movement_multiplier.unwrap_or(0);
-                self.movement_multiplier = Some(current_multiplier * 10 + digit);
-                return Ok(());
+    /// Adds a digit key to the pending count and returns `None`, or takes the count for any
+    /// other key, defaulting to 1.
+    fn take_count(&mut self, key: KeyEvent) -> Option<usize> {
+        if let KeyCode::Char(c) = key.code
+            && c.is_ascii_digit()
+        {
+            let digit = c.to_digit(10).unwrap() as usize;
+            let current_multiplier = self.movement_multiplier.unwrap_or(0);
+            self.movement_multiplier = Some(current_multiplier * 10 + digit);
+            return None;
         }
+        Some(self.movement_multiplier.take().unwrap_or(1))
+    }
 
-        let count = self.movement_multiplier.take().unwrap_or(1);
+    pub async fn handle_file_view_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(count) = self.take_count(key) else {
+            return Ok(());
+        };
 
         match key.code {
             KeyCode::Up | KeyCode::Char('k') => {
@@ -193,8 +204,16 @@ impl App {
                 self.cursor_line = self.file_content.lines().count().saturating_sub(1);
                 self.adjust_scroll();
             }
-            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Char('q') | KeyCode::Esc => self.request_quit(),
             KeyCode::Char('e') => self.execute_current_query().await?,
+            KeyCode::Char('x') => self.explain_current_query(ExplainMode::Estimate)?,
+            KeyCode::Char('A') => self.explain_current_query(ExplainMode::Analyze)?,
+            KeyCode::Char('B') => self.begin_transaction()?,
+            KeyCode::Char('C') => self.commit_transaction()?,
+            KeyCode::Char('X') => self.rollback_transaction()?,
+            KeyCode::Char('S') => self.open_schema_browser(),
+            KeyCode::Char('P') => self.open_profile_picker(),
+            KeyCode::Char('L') => self.open_channel_monitor(),
             KeyCode::Char('?') => {
                 self.previous_mode = Some(self.current_mode.clone());
                 self.current_mode = AppMode::Help;
@@ -204,6 +223,49 @@ impl App {
         Ok(())
     }
 
+    pub async fn handle_parameter_prompt_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(prompt) = &mut self.parameter_prompt else {
+            self.current_mode = AppMode::FileView;
+            return Ok(());
+        };
+        match key.code {
+            KeyCode::Enter => self.submit_parameter_prompt()?,
+            KeyCode::Esc => self.cancel_parameter_prompt(),
+            KeyCode::Tab | KeyCode::Down => prompt.select_next(),
+            KeyCode::BackTab | KeyCode::Up => prompt.select_previous(),
+            KeyCode::Backspace => prompt.pop(),
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => prompt.clear(),
+            KeyCode::Char(c) => prompt.push(c),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub async fn handle_profile_picker_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(picker) = &mut self.profile_picker else {
+            self.current_mode = AppMode::FileView;
+            return Ok(());
+        };
+        match key.code {
+            KeyCode::Enter => self.switch_to_selected_profile()?,
+            KeyCode::Char('q') | KeyCode::Esc => self.close_profile_picker(),
+            KeyCode::Down | KeyCode::Char('j') => picker.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => picker.select_previous(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub async fn handle_confirm_quit_keys(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Char('c') => self.end_transaction_and_quit("COMMIT"),
+            KeyCode::Char('r') => self.end_transaction_and_quit("ROLLBACK"),
+            KeyCode::Char('n') | KeyCode::Esc => self.cancel_quit(),
+            _ => {}
+        }
+        Ok(())
+    }
+
     pub async fn handle_table_viewer_keys(&mut self, key: KeyEvent) -> Result<()> {
         if !self.yank_sequence.is_empty() {
             match key.code {
@@ -302,30 +364,13 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('F') => {
-                if let Some(viewer) = &self.table_viewer {
-                    match viewer.lookup_foreign_key(self).await {
-                        Ok(Some(new_viewer)) => {
-                            self.foreign_key_viewer = Some(new_viewer);
-                            self.current_mode = AppMode::ForeignKeyView;
-                        }
-                        Ok(None) => {
-                            self.ui.add_toast(
-                                "No foreign key data found for this cell".to_string(),
-                                ToastType::Info,
-                            );
-                        }
-                        Err(e) => {
-                            self.ui
-                                .add_toast(format!("FK lookup failed: {}", e), ToastType::Error);
-                        }
-                    }
-                }
-            }
+            KeyCode::Char('F') => self.follow_foreign_key().await?,
             KeyCode::Char('/') => {
                 self.search_input.clear();
                 self.current_mode = AppMode::Searching;
             }
+            KeyCode::Char('M') => self.fetch_more_rows()?,
+            KeyCode::Char('R') => self.open_references_view().await?,
             _ => {
                 if let Some(viewer) = &mut self.table_viewer {
                     match key.code {
@@ -368,22 +413,12 @@ impl App {
                 self.current_mode = AppMode::TableViewer;
             }
             KeyCode::Char('F') => {
-                if let Some(cell_info) = &self.cell_info &&
-                    cell_info.foreign_key_info.is_some() &&
-                        let Some(viewer) = &self.table_viewer {
-                            match viewer.lookup_foreign_key(self).await {
-                                Ok(Some(new_viewer)) => {
-                                    self.foreign_key_viewer = Some(new_viewer);
-                                    self.current_mode = AppMode::ForeignKeyView;
-                                }
-                                Ok(None) => {}
-                                Err(e) => {
-                                    self.ui.add_toast(
-                                        format!("FK lookup failed: {}", e),
-                                        ToastType::Error,
-                                    );
-                                }
-                            }
+                if let Some(cell_info) = &self.cell_info
+                    && cell_info.foreign_key_info.is_some()
+                {
+                    self.cell_info = None;
+                    self.current_mode = AppMode::TableViewer;
+                    self.follow_foreign_key().await?;
                 }
             }
             KeyCode::Char('?') => {
@@ -394,4 +429,174 @@ impl App {
         }
         Ok(())
     }
+
+    pub async fn handle_schema_browser_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(count) = self.take_count(key) else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.close_schema_browser(),
+            KeyCode::Char('r') => self.load_schema(),
+            KeyCode::Char('?') => {
+                self.previous_mode = Some(self.current_mode.clone());
+                self.current_mode = AppMode::Help;
+            }
+            KeyCode::Enter | KeyCode::Char('p') => self.preview_selected_table().await?,
+            _ => {
+                let Some(browser) = &mut self.schema_browser else {
+                    return Ok(());
+                };
+                match key.code {
+                    KeyCode::Down | KeyCode::Char('j') => browser.move_down(count),
+                    KeyCode::Up | KeyCode::Char('k') => browser.move_up(count),
+                    KeyCode::PageDown => browser.move_down(count * 10),
+                    KeyCode::PageUp => browser.move_up(count * 10),
+                    KeyCode::Home | KeyCode::Char('g') => browser.selected = 0,
+                    KeyCode::End | KeyCode::Char('G') => browser.select_last(),
+                    KeyCode::Right | KeyCode::Char('l') => browser.expand(),
+                    KeyCode::Left | KeyCode::Char('h') => browser.collapse(),
+                    KeyCode::Char(' ') => browser.toggle(),
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn handle_plan_viewer_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(count) = self.take_count(key) else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.close_plan_viewer(),
+            KeyCode::Char('?') => {
+                self.previous_mode = Some(self.current_mode.clone());
+                self.current_mode = AppMode::Help;
+            }
+            _ => {
+                let Some(viewer) = &mut self.plan_viewer else {
+                    return Ok(());
+                };
+                match key.code {
+                    KeyCode::Down | KeyCode::Char('j') => viewer.move_down(count),
+                    KeyCode::Up | KeyCode::Char('k') => viewer.move_up(count),
+                    KeyCode::PageDown => viewer.move_down(count * 10),
+                    KeyCode::PageUp => viewer.move_up(count * 10),
+                    KeyCode::Home | KeyCode::Char('g') => viewer.selected = 0,
+                    KeyCode::End | KeyCode::Char('G') => viewer.select_last(),
+                    KeyCode::Right | KeyCode::Char('l') => viewer.expand(),
+                    KeyCode::Left | KeyCode::Char('h') => viewer.collapse(),
+                    KeyCode::Char(' ') | KeyCode::Enter => viewer.toggle(),
+                    KeyCode::Char('n') => viewer.select_next_expensive(),
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn handle_channel_monitor_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(monitor) = &mut self.channel_monitor else {
+            self.current_mode = AppMode::FileView;
+            return Ok(());
+        };
+
+        if let Some(input) = &mut monitor.input {
+            match key.code {
+                KeyCode::Enter => {
+                    let channels = monitor.take_input();
+                    if !channels.is_empty() {
+                        self.listen_to_channels(channels);
+                    } else if !monitor.is_listening() {
+                        self.stop_listening();
+                    }
+                }
+                KeyCode::Esc => {
+                    monitor.input = None;
+                    if !monitor.is_listening() {
+                        self.stop_listening();
+                    }
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    input.clear()
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let Some(count) = self.take_count(key) else {
+            return Ok(());
+        };
+        let Some(monitor) = &mut self.channel_monitor else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.close_channel_monitor(),
+            KeyCode::Char('s') => {
+                self.stop_listening();
+                self.ui
+                    .add_toast("Stopped listening".to_string(), ToastType::Info);
+            }
+            KeyCode::Char('y') => {
+                if let Some(notification) = monitor.selected_notification() {
+                    let payload = notification.payload.clone();
+                    match self.clipboard.set_contents(payload) {
+                        Ok(_) => self
+                            .ui
+                            .add_toast("Yanked payload".to_string(), ToastType::Success),
+                        Err(e) => self.ui.add_toast(
+                            format!("Failed to copy to clipboard: {}", e),
+                            ToastType::Error,
+                        ),
+                    }
+                }
+            }
+            KeyCode::Char('?') => {
+                self.previous_mode = Some(self.current_mode.clone());
+                self.current_mode = AppMode::Help;
+            }
+            KeyCode::Down | KeyCode::Char('j') => monitor.move_down(count),
+            KeyCode::Up | KeyCode::Char('k') => monitor.move_up(count),
+            KeyCode::PageDown => monitor.move_down(count * 10),
+            KeyCode::PageUp => monitor.move_up(count * 10),
+            KeyCode::Home | KeyCode::Char('g') => monitor.selected = 0,
+            KeyCode::End | KeyCode::Char('G') => monitor.select_last(),
+            KeyCode::Char('a') => monitor.edit_channels(),
+            KeyCode::Char('c') => monitor.clear(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub async fn handle_references_keys(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(view) = &mut self.references_view else {
+            self.close_references_view();
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.close_references_view(),
+            KeyCode::Char('j') | KeyCode::Down => {
+                view.selected = (view.selected + 1).min(view.entries.len().saturating_sub(1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                view.selected = view.selected.saturating_sub(1);
+            }
+            KeyCode::Enter => self.open_selected_reference().await?,
+            KeyCode::Char('?') => {
+                self.previous_mode = Some(self.current_mode.clone());
+                self.current_mode = AppMode::Help;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
 }
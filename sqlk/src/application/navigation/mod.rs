@@ -0,0 +1,150 @@
+use anyhow::Result;
+
+use crate::{
+    application::{app::App, state::AppMode},
+    database::DatabaseManager,
+    table_viewer::{ForeignKeyStep, ReferencesView, TableViewer},
+    ui::ToastType,
+};
+
+impl App {
+    /// The viewer that navigation keys act on: the current foreign key step while one is
+    /// open, otherwise the main result.
+    pub fn active_viewer(&self) -> Option<&TableViewer> {
+        match self.current_mode {
+            AppMode::ForeignKeyView => self.fk_navigation.current(),
+            _ => self.table_viewer.as_ref(),
+        }
+    }
+
+    pub async fn follow_foreign_key(&mut self) -> Result<()> {
+        let Some(viewer) = self.active_viewer() else {
+            return Ok(());
+        };
+        let Some(fk_info) = viewer.foreign_keys.get(&viewer.current_col).cloned() else {
+            self.ui
+                .add_toast("No foreign key on this column".to_string(), ToastType::Info);
+            return Ok(());
+        };
+
+        match viewer.lookup_foreign_key(self).await {
+            Ok(Some(new_viewer)) => self.push_foreign_key_step(ForeignKeyStep {
+                source: fk_info.source_label(),
+                target: fk_info.referenced_table.clone(),
+                viewer: new_viewer,
+            }),
+            Ok(None) => {
+                self.ui.add_toast(
+                    "No foreign key data found for this cell".to_string(),
+                    ToastType::Info,
+                );
+            }
+            Err(e) => {
+                self.ui
+                    .add_toast(format!("FK lookup failed: {}", e), ToastType::Error);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn open_references_view(&mut self) -> Result<()> {
+        let Some(viewer) = self.active_viewer() else {
+            return Ok(());
+        };
+        let db_manager = self
+            .db_manager
+            .get_or_try_init(|| async { DatabaseManager::new(&self.config).await })
+            .await?;
+
+        match viewer.find_referencing_keys(db_manager).await {
+            Ok(entries) if entries.is_empty() => {
+                self.ui.add_toast(
+                    "No foreign keys reference this row".to_string(),
+                    ToastType::Info,
+                );
+            }
+            Ok(entries) => {
+                self.references_view = Some(ReferencesView {
+                    entries,
+                    selected: 0,
+                });
+                self.current_mode = AppMode::ReferencesView;
+            }
+            Err(e) => {
+                self.ui
+                    .add_toast(format!("Reverse lookup failed: {}", e), ToastType::Error);
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn open_selected_reference(&mut self) -> Result<()> {
+        let Some(entry) = self
+            .references_view
+            .as_ref()
+            .and_then(|view| view.entries.get(view.selected))
+        else {
+            return Ok(());
+        };
+        let db_manager = self
+            .db_manager
+            .get_or_try_init(|| async { DatabaseManager::new(&self.config).await })
+            .await?;
+
+        let fk_info = &entry.foreign_key_info;
+        let result = db_manager
+            .lookup_referencing_rows(fk_info, &entry.key_values)
+            .await
+            .and_then(|result| TableViewer::new(result, &self.config, db_manager));
+        match result {
+            Ok(new_viewer) => {
+                let step = ForeignKeyStep {
+                    source: fk_info.referenced_label(),
+                    target: fk_info.source_label(),
+                    viewer: new_viewer,
+                };
+                self.references_view = None;
+                self.push_foreign_key_step(step);
+            }
+            Err(e) => {
+                self.ui
+                    .add_toast(format!("Reverse lookup failed: {}", e), ToastType::Error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Leaves the references list for whichever view it was opened from.
+    pub fn close_references_view(&mut self) {
+        self.references_view = None;
+        self.current_mode = if self.fk_navigation.is_empty() {
+            AppMode::TableViewer
+        } else {
+            AppMode::ForeignKeyView
+        };
+    }
+
+    pub fn navigate_back(&mut self) {
+        if !self.fk_navigation.back() {
+            self.ui
+                .add_toast("Already at the first lookup".to_string(), ToastType::Info);
+        }
+    }
+
+    pub fn navigate_forward(&mut self) {
+        if !self.fk_navigation.forward() {
+            self.ui
+                .add_toast("No newer lookup".to_string(), ToastType::Info);
+        }
+    }
+
+    pub fn close_foreign_key_view(&mut self) {
+        self.fk_navigation.clear();
+        self.current_mode = AppMode::TableViewer;
+    }
+
+    fn push_foreign_key_step(&mut self, step: ForeignKeyStep) {
+        self.fk_navigation.push(step);
+        self.current_mode = AppMode::ForeignKeyView;
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::Result;
+
+use crate::{
+    application::{app::App, state::AppMode},
+    database::QueryParam,
+    query_plan::ExplainMode,
+};
+
+/// Values being entered for a query's placeholders before it runs.
+#[derive(Debug, Clone)]
+pub struct ParameterPrompt {
+    pub query: String,
+    pub names: Vec<String>,
+    pub values: Vec<String>,
+    pub selected: usize,
+    /// Set when the values are for explaining the query rather than running it.
+    pub explain: Option<ExplainMode>,
+}
+
+impl ParameterPrompt {
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % self.names.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + self.names.len() - 1) % self.names.len();
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.values[self.selected].push(c);
+    }
+
+    pub fn pop(&mut self) {
+        self.values[self.selected].pop();
+    }
+
+    pub fn clear(&mut self) {
+        self.values[self.selected].clear();
+    }
+}
+
+impl App {
+    /// Asks for a value per placeholder, pre-filled with the last value given for that name.
+    pub fn open_parameter_prompt(
+        &mut self,
+        query: String,
+        names: Vec<String>,
+        explain: Option<ExplainMode>,
+    ) {
+        let values = names
+            .iter()
+            .map(|name| {
+                self.parameter_history
+                    .get(name)
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect();
+        self.parameter_prompt = Some(ParameterPrompt {
+            query,
+            names,
+            values,
+            selected: 0,
+            explain,
+        });
+        self.current_mode = AppMode::ParameterPrompt;
+    }
+
+    pub fn submit_parameter_prompt(&mut self) -> Result<()> {
+        let Some(prompt) = self.parameter_prompt.take() else {
+            return Ok(());
+        };
+        self.current_mode = AppMode::FileView;
+
+        let params: Vec<QueryParam> = prompt
+            .names
+            .into_iter()
+            .zip(prompt.values)
+            .map(|(name, value)| QueryParam::new(name, value))
+            .collect();
+        for param in &params {
+            self.parameter_history
+                .insert(param.name.clone(), param.input.clone());
+        }
+        match prompt.explain {
+            Some(mode) => self.explain_query(&prompt.query, params, mode),
+            None => self.execute_query_with_params(&prompt.query, params),
+        }
+    }
+
+    pub fn cancel_parameter_prompt(&mut self) {
+        self.parameter_prompt = None;
+        self.current_mode = AppMode::FileView;
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::Result;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+use crate::{
+    application::{
+        app::App,
+        state::{AppMessage, AppMode},
+    },
+    database::DatabaseManager,
+    table_viewer::ForeignKeyNavigation,
+    ui::ToastType,
+};
+
+/// The connection profiles offered for switching, in name order.
+#[derive(Debug, Clone)]
+pub struct ProfilePicker {
+    pub names: Vec<String>,
+    pub selected: usize,
+}
+
+impl ProfilePicker {
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % self.names.len();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = (self.selected + self.names.len() - 1) % self.names.len();
+    }
+
+    pub fn selected_name(&self) -> &str {
+        &self.names[self.selected]
+    }
+}
+
+impl App {
+    pub fn open_profile_picker(&mut self) {
+        if self.config.connections.is_empty() {
+            self.ui.add_toast(
+                "No [connections.<name>] profiles in the config file".to_string(),
+                ToastType::Info,
+            );
+            return;
+        }
+        let names: Vec<String> = self.config.connections.keys().cloned().collect();
+        let selected = self
+            .config
+            .active_profile
+            .as_ref()
+            .and_then(|active| names.iter().position(|name| name == active))
+            .unwrap_or(0);
+        self.profile_picker = Some(ProfilePicker { names, selected });
+        self.current_mode = AppMode::ProfilePicker;
+    }
+
+    pub fn close_profile_picker(&mut self) {
+        self.profile_picker = None;
+        self.current_mode = AppMode::FileView;
+    }
+
+    pub fn switch_to_selected_profile(&mut self) -> Result<()> {
+        let Some(picker) = self.profile_picker.take() else {
+            return Ok(());
+        };
+        self.current_mode = AppMode::FileView;
+        self.switch_profile(picker.selected_name())
+    }
+
+    /// Drops the current connection pool and connects to the named profile instead. Results
+    /// and schema from the old connection are discarded with it.
+    pub fn switch_profile(&mut self, name: &str) -> Result<()> {
+        if self.is_querying {
+            self.ui
+                .add_toast("A query is already running".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        // Results still on their way from the old connection would land under the new one.
+        if self.is_loading_schema || self.is_connecting {
+            self.ui.add_toast(
+                "Wait for the current connection to finish loading before switching".to_string(),
+                ToastType::Info,
+            );
+            return Ok(());
+        }
+        if self.in_transaction() {
+            self.ui.add_toast(
+                "Commit or roll back the open transaction before switching connections".to_string(),
+                ToastType::Warning,
+            );
+            return Ok(());
+        }
+
+        self.config.use_profile(name)?;
+        self.db_manager = Arc::new(OnceCell::new());
+        self.table_viewer = None;
+        self.fk_navigation = ForeignKeyNavigation::default();
+        self.cell_info = None;
+        self.references_view = None;
+        self.schema_browser = None;
+        self.last_outcome = None;
+        self.stop_listening();
+        self.connect_database();
+        Ok(())
+    }
+
+    /// Connects in the background; the result arrives as `Connected`.
+    pub(crate) fn connect_database(&mut self) {
+        self.is_connecting = true;
+        let config = self.config.clone();
+        let app_tx = self.app_tx.clone();
+        let db_man_clone = self.db_manager.clone();
+
+        tokio::spawn(async move {
+            let name = config
+                .active_profile
+                .clone()
+                .unwrap_or_else(|| "the database".to_string());
+            let result = db_man_clone
+                .get_or_try_init(|| async { DatabaseManager::new(&config).await })
+                .await
+                .map(|db_manager| (name.clone(), db_manager.foreign_key_warnings().to_vec()))
+                .map_err(|e| format!("Failed to connect to {}: {}", name, e));
+            let _ = app_tx.send(AppMessage::Connected(result)).await;
+        });
+    }
+}
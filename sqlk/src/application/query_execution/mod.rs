@@ -1,13 +1,27 @@
 use anyhow::Result;
+use std::time::Duration;
 
 use crate::application::app::App;
 use crate::application::state::{AppMessage, AppMode};
-use crate::database::DatabaseManager;
+use crate::database::notice::notices_of;
+use crate::database::timeout::timeout_cause;
+use crate::database::{DatabaseManager, NoticeSeverity, QueryParam, ServerNotice};
 use crate::table_viewer::TableViewer;
 use crate::ui::ToastType;
 
+const SERVER_CANCEL_TIMEOUT: Duration = Duration::from_secs(3);
+
 impl App {
     pub fn execute_query(&mut self, query: &str) -> Result<()> {
+        self.execute_query_with_params(query, Vec::new())
+    }
+
+    /// Runs `query` in the background with `params` bound to its placeholders.
+    pub fn execute_query_with_params(
+        &mut self,
+        query: &str,
+        params: Vec<QueryParam>,
+    ) -> Result<()> {
         self.is_querying = true;
 
         if self.config.matrix.enabled && self.startup_complete {
@@ -20,7 +34,7 @@ impl App {
         let app_tx = self.app_tx.clone();
         let db_man_clone = self.db_manager.clone();
 
-        tokio::spawn(async move {
+        self.query_task = Some(tokio::spawn(async move {
             let db_manager = db_man_clone
                 .get_or_try_init(|| async {
                     DatabaseManager::new(&config).await
@@ -28,16 +42,35 @@ impl App {
                 .await
                 .map_err(|e| anyhow::anyhow!("Database manager initialization failed: {}", e))
                 .expect("Failed to get db_manager");
-            let result = match db_manager.execute_query(&query_string).await {
+            let message = match db_manager
+                .execute_query_with_params(&query_string, &params)
+                .await
+            {
                 Ok(query_result) => match TableViewer::new(query_result, &config, db_manager) {
-                    Ok(viewer) => Ok(viewer),
-                    Err(e) => Err(format!("Failed to create table viewer: {}", e)),
+                    Ok(viewer) => AppMessage::QueryResult(Ok(Box::new(viewer))),
+                    Err(e) => AppMessage::QueryResult(Err(format!(
+                        "Failed to create table viewer: {}",
+                        e
+                    ))),
                 },
-                Err(e) => Err(format!("Query execution failed: {}", e)),
+                Err(e) => {
+                    let notices = notices_of(&e);
+                    if !notices.is_empty() {
+                        let _ = app_tx
+                            .send(AppMessage::ServerNotices(notices.to_vec()))
+                            .await;
+                    }
+                    match timeout_cause(&e) {
+                        Some(cause) => AppMessage::QueryTimedOut(timeout_message(cause, &e)),
+                        None => {
+                            AppMessage::QueryResult(Err(format!("Query execution failed: {}", e)))
+                        }
+                    }
+                }
             };
 
-            let _ = app_tx.send(AppMessage::QueryResult(result)).await;
-        });
+            let _ = app_tx.send(message).await;
+        }));
 
         Ok(())
     }
@@ -59,20 +92,7 @@ impl App {
 
         match db_manager.execute_query(query).await {
             Ok(result) => match TableViewer::new(result, &self.config, db_manager) {
-                Ok(viewer) => {
-                    self.table_viewer = Some(viewer);
-
-                    if self.matrix_animation.is_none() {
-                        self.current_mode = AppMode::TableViewer;
-                    }
-
-                    if self.startup_complete || !self.config.matrix.enabled {
-                        self.ui.add_toast(
-                            "Query executed successfully".to_string(),
-                            ToastType::Success,
-                        );
-                    }
-                }
+                Ok(viewer) => self.show_query_viewer(viewer),
                 Err(e) => {
                     self.ui.add_toast(
                         format!("Failed to create table viewer: {}", e),
@@ -82,8 +102,15 @@ impl App {
                 }
             },
             Err(e) => {
-                self.ui
-                    .add_toast(format!("Query execution failed: {}", e), ToastType::Error);
+                self.show_server_notices(notices_of(&e));
+                match timeout_cause(&e) {
+                    Some(cause) => self
+                        .ui
+                        .add_toast(timeout_message(cause, &e), ToastType::Warning),
+                    None => self
+                        .ui
+                        .add_toast(format!("Query execution failed: {}", e), ToastType::Error),
+                }
                 self.current_mode = AppMode::FileView;
                 self.matrix_animation = None;
             }
@@ -92,6 +119,126 @@ impl App {
         Ok(())
     }
 
+    /// Opens a finished query's results, or reports the outcome of a statement without any.
+    pub fn show_query_viewer(&mut self, viewer: TableViewer) {
+        let summary = viewer.data.outcome_summary();
+        if let Some(summary) = &summary {
+            self.last_outcome = Some(summary.clone());
+        }
+
+        let notices = viewer.data.notices.clone();
+        if summary.is_some() && viewer.data.headers.is_empty() && notices.is_empty() {
+            // Nothing to show in a grid, so the previous results stay open.
+            if self.matrix_animation.is_none() {
+                self.current_mode = AppMode::FileView;
+            }
+        } else {
+            self.table_viewer = Some(viewer);
+            if self.matrix_animation.is_none() {
+                self.current_mode = AppMode::TableViewer;
+            }
+        }
+
+        if self.startup_complete || !self.config.matrix.enabled {
+            self.ui.add_toast(
+                summary.unwrap_or_else(|| "Query executed successfully".to_string()),
+                ToastType::Success,
+            );
+        }
+        self.show_server_notices(&notices);
+    }
+
+    /// Toasts the first few server notices; the rest are left to the messages panel.
+    pub fn show_server_notices(&mut self, notices: &[ServerNotice]) {
+        const TOASTED_NOTICES: usize = 3;
+
+        for notice in notices.iter().take(TOASTED_NOTICES) {
+            let toast_type = match notice.severity {
+                NoticeSeverity::Warning => ToastType::Warning,
+                _ => ToastType::Info,
+            };
+            self.ui.add_toast(notice.to_string(), toast_type);
+        }
+        if notices.len() > TOASTED_NOTICES {
+            self.ui.add_toast(
+                format!("{} more server messages", notices.len() - TOASTED_NOTICES),
+                ToastType::Info,
+            );
+        }
+    }
+
+    /// Reads another page of rows from the result the viewer shows, which the client keeps
+    /// open while it is truncated.
+    pub fn fetch_more_rows(&mut self) -> Result<()> {
+        let Some(viewer) = &self.table_viewer else {
+            return Ok(());
+        };
+        if !viewer.is_truncated() {
+            self.ui
+                .add_toast("All rows already loaded".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        if self.is_querying {
+            return Ok(());
+        }
+
+        self.is_querying = true;
+        self.ui
+            .add_toast("Fetching more rows".to_string(), ToastType::Info);
+
+        let config = self.config.clone();
+        let app_tx = self.app_tx.clone();
+        let db_man_clone = self.db_manager.clone();
+
+        self.query_task = Some(tokio::spawn(async move {
+            let result = match db_man_clone
+                .get_or_try_init(|| async { DatabaseManager::new(&config).await })
+                .await
+            {
+                Ok(db_manager) => db_manager
+                    .fetch_more()
+                    .await
+                    .map_err(|e| format!("Fetching more rows failed: {}", e)),
+                Err(e) => Err(format!("Database manager initialization failed: {}", e)),
+            };
+
+            let _ = app_tx.send(AppMessage::MoreRows(result)).await;
+        }));
+
+        Ok(())
+    }
+
+    pub fn cancel_query(&mut self) {
+        let Some(task) = self.query_task.take() else {
+            return;
+        };
+        if task.is_finished() {
+            return;
+        }
+
+        // The aborted statement keeps running on the server until it is told to stop, and its
+        // backend has to be picked while the task is still there to say which one it is.
+        let server_cancel = self.db_manager.get().map(DatabaseManager::cancel_query);
+        task.abort();
+        self.is_querying = false;
+        self.ui
+            .add_toast("Query cancelled".to_string(), ToastType::Info);
+
+        let Some(server_cancel) = server_cancel else {
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let result = match tokio::time::timeout(SERVER_CANCEL_TIMEOUT, server_cancel).await {
+                Ok(result) => result.map_err(|e| e.to_string()),
+                Err(_) => Err("timed out".to_string()),
+            };
+            if let Err(e) = result {
+                let _ = app_tx.send(AppMessage::ServerCancelFailed(e)).await;
+            }
+        });
+    }
+
     fn validate_query(&self, query: &str) -> Result<()> {
         if query.trim().is_empty() {
             Err(anyhow::anyhow!("Query is empty"))
@@ -103,7 +250,10 @@ impl App {
     pub async fn execute_current_query(&mut self) -> Result<()> {
         if let Some(query_block) = self.get_current_query_block() {
             let query_text = query_block.text.clone();
-            if !query_text.trim().is_empty() {
+            if !query_block.placeholders.is_empty() {
+                let names = query_block.placeholders.clone();
+                self.open_parameter_prompt(query_text, names, None);
+            } else if !query_text.trim().is_empty() {
                 self.execute_query(&query_text)?;
             } else {
                 self.ui.add_toast(
@@ -120,3 +270,7 @@ impl App {
         Ok(())
     }
 }
+
+pub(crate) fn timeout_message(cause: &str, error: &anyhow::Error) -> String {
+    format!("Query timed out ({}): {}", cause, error)
+}
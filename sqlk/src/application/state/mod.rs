@@ -1,4 +1,6 @@
 
+use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::sync::OnceCell;
 
 use anyhow::Result;
@@ -7,8 +9,11 @@ use tokio::sync::mpsc;
 
 use crate::application::app::App;
 use crate::args::Args;
+use crate::channel_monitor::ChannelNotification;
+use crate::database::{QueryResult, SchemaInfo, ServerNotice};
+use crate::query_plan::QueryPlan;
 // use crate::database::DatabaseManager;
-use crate::table_viewer::TableViewer;
+use crate::table_viewer::{ForeignKeyNavigation, TableViewer};
 use crate::ui::UI;
 use crate::{
     config::Config,
@@ -23,7 +28,14 @@ pub enum AppMode {
     Help,
     ForeignKeyView,
     CellInfoView,
+    ReferencesView,
+    SchemaBrowser,
     Searching,
+    ParameterPrompt,
+    ConfirmQuit,
+    ProfilePicker,
+    PlanViewer,
+    ChannelMonitor,
 }
 
 #[derive(Debug)]
@@ -33,10 +45,29 @@ pub struct StartupResult {
     pub success_message: Option<String>,
     pub query_blocks: Vec<QueryBlock>,
     pub error_message: Option<String>,
+    pub warning_messages: Vec<String>,
 }
 
 pub enum AppMessage {
-    QueryResult(Result<TableViewer, String>),
+    QueryResult(Result<Box<TableViewer>, String>),
+    /// A query cut short by a configured timeout rather than an error in the query itself.
+    QueryTimedOut(String),
+    /// Notices the server sent before a statement failed, delivered ahead of its error.
+    ServerNotices(Vec<ServerNotice>),
+    MoreRows(Result<QueryResult, String>),
+    /// A cancelled query that the server could not be told to stop.
+    ServerCancelFailed(String),
+    SchemaLoaded(Result<Vec<SchemaInfo>, String>),
+    PlanLoaded(Result<Box<QueryPlan>, String>),
+    /// The channels a new listener subscribed to.
+    Listening(Result<Vec<String>, String>),
+    ChannelNotification(ChannelNotification),
+    /// The listener lost its connection and could not get it back.
+    ListenerStopped(String),
+    /// The profile connected to, with any foreign key warnings from loading its metadata.
+    Connected(Result<(String, Vec<String>), String>),
+    /// The `COMMIT` or `ROLLBACK` sent on the way out.
+    TransactionEnded(Result<String, String>),
 }
 
 impl App {
@@ -53,9 +84,12 @@ impl App {
     }
 
     pub fn new(args: Args) -> Result<Self> {
-        let config = Config::load(&args.env, args.toast_level)?;
+        let mut config = Config::load(&args.env, args.toast_level)?;
+        if let Some(profile) = &args.profile {
+            config.use_profile(profile)?;
+        }
         let toast_level: String = config.clone().toast_level.clone();
-        let db_manager = OnceCell::new(); 
+        let db_manager = Arc::new(OnceCell::new());
         let (app_tx, app_rx) = mpsc::channel(1);
         let app = Self {
             config,
@@ -66,11 +100,12 @@ impl App {
             file_content: String::new(),
             matrix_animation: None,
             table_viewer: None,
-            foreign_key_viewer: None,
+            fk_navigation: ForeignKeyNavigation::default(),
             search_input: String::new(),
             movement_multiplier: None,
             ui: UI::new(toast_level),
             should_quit: false,
+            quit_pending: false,
             cursor_line: 0,
             app_tx,
             app_rx,
@@ -81,7 +116,19 @@ impl App {
             pending_query: args.query.clone(),
             file_loading_complete: false,
             startup_task: None,
+            query_task: None,
+            last_outcome: None,
             cell_info: None,
+            references_view: None,
+            schema_browser: None,
+            is_loading_schema: false,
+            is_connecting: false,
+            parameter_prompt: None,
+            parameter_history: HashMap::new(),
+            profile_picker: None,
+            plan_viewer: None,
+            channel_monitor: None,
+            listener_task: None,
             query_parser: QueryParser::new(),
             query_blocks: Vec::new(),
             is_querying: false,
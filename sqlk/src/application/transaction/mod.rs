@@ -0,0 +1,110 @@
+use anyhow::Result;
+
+use crate::{
+    application::{
+        app::App,
+        state::{AppMessage, AppMode},
+    },
+    ui::ToastType,
+};
+
+impl App {
+    pub fn in_transaction(&self) -> bool {
+        self.db_manager
+            .get()
+            .is_some_and(|db_manager| db_manager.in_transaction())
+    }
+
+    pub fn begin_transaction(&mut self) -> Result<()> {
+        if self.in_transaction() {
+            self.ui
+                .add_toast("A transaction is already open".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        self.run_transaction_statement("BEGIN")
+    }
+
+    pub fn commit_transaction(&mut self) -> Result<()> {
+        self.end_transaction("COMMIT")
+    }
+
+    pub fn rollback_transaction(&mut self) -> Result<()> {
+        self.end_transaction("ROLLBACK")
+    }
+
+    /// Quits straight away unless a transaction is open, in which case the user decides
+    /// whether to commit or roll it back first.
+    pub fn request_quit(&mut self) {
+        if self.in_transaction() {
+            self.previous_mode = Some(self.current_mode.clone());
+            self.current_mode = AppMode::ConfirmQuit;
+        } else {
+            self.should_quit = true;
+        }
+    }
+
+    /// Ends the open transaction with `statement` in the background. It may have to wait for
+    /// a query still running in the transaction; `TransactionEnded` quits once it is done.
+    pub fn end_transaction_and_quit(&mut self, statement: &str) {
+        let Some(db_manager) = self.db_manager.get().cloned() else {
+            self.should_quit = true;
+            return;
+        };
+        self.quit_pending = true;
+        self.ui.add_toast(
+            format!("Running {} before quitting", statement),
+            ToastType::Info,
+        );
+
+        let statement = statement.to_string();
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let result = db_manager
+                .execute_query(&statement)
+                .await
+                .map(|_| statement.clone())
+                .map_err(|e| format!("{} failed: {}", statement, e));
+            let _ = app_tx.send(AppMessage::TransactionEnded(result)).await;
+        });
+    }
+
+    /// Quits after the transaction ended, unless the user has since decided to stay.
+    pub fn finish_quit(&mut self, result: Result<String, String>) {
+        match result {
+            Ok(_) if self.quit_pending => self.should_quit = true,
+            Ok(statement) => self
+                .ui
+                .add_toast(format!("{} done", statement), ToastType::Success),
+            Err(e) => {
+                self.ui.add_toast(e, ToastType::Error);
+                if self.current_mode == AppMode::ConfirmQuit {
+                    self.cancel_quit();
+                }
+            }
+        }
+        self.quit_pending = false;
+    }
+
+    pub fn cancel_quit(&mut self) {
+        self.quit_pending = false;
+        self.current_mode = self.previous_mode.take().unwrap_or(AppMode::FileView);
+    }
+
+    fn end_transaction(&mut self, statement: &str) -> Result<()> {
+        if !self.in_transaction() {
+            self.ui
+                .add_toast("No transaction is open".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        self.run_transaction_statement(statement)
+    }
+
+    fn run_transaction_statement(&mut self, statement: &str) -> Result<()> {
+        if self.is_querying {
+            self.ui
+                .add_toast("A query is already running".to_string(), ToastType::Info);
+            return Ok(());
+        }
+        self.execute_query(statement)
+    }
+}
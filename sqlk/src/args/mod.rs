@@ -23,4 +23,8 @@ pub struct Args {
     /// Direct SQL query to execute
     #[arg(short, long)]
     pub query: Option<String>,
+
+    /// Connection profile to use, from a `[connections.<name>]` table in the config
+    #[arg(short, long)]
+    pub profile: Option<String>,
 }
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Local};
+
+/// Oldest notifications are dropped past this many, so a busy channel cannot grow the
+/// monitor without bound.
+const MAX_NOTIFICATIONS: usize = 1000;
+
+#[derive(Debug, Clone)]
+pub struct ChannelNotification {
+    pub channel: String,
+    pub payload: String,
+    /// Backend pid of the session that sent the notification.
+    pub pid: u32,
+    pub received_at: DateTime<Local>,
+}
+
+impl ChannelNotification {
+    pub fn new(channel: String, payload: String, pid: u32) -> Self {
+        Self {
+            channel,
+            payload,
+            pid,
+            received_at: Local::now(),
+        }
+    }
+
+    /// The payload indented for reading when it is a JSON document, and as sent otherwise.
+    pub fn pretty_payload(&self) -> String {
+        match serde_json::from_str::<serde_json::Value>(&self.payload) {
+            Ok(json) if json.is_object() || json.is_array() => {
+                serde_json::to_string_pretty(&json).unwrap_or_else(|_| self.payload.clone())
+            }
+            _ => self.payload.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ChannelMonitor {
+    /// Channels the listener is subscribed to; empty until the first subscription succeeds.
+    pub channels: Vec<String>,
+    pub notifications: Vec<ChannelNotification>,
+    pub selected: usize,
+    /// Channel names being typed, while the channel prompt is open.
+    pub input: Option<String>,
+}
+
+impl ChannelMonitor {
+    pub fn new() -> Self {
+        Self {
+            input: Some(String::new()),
+            ..Self::default()
+        }
+    }
+
+    pub fn is_listening(&self) -> bool {
+        !self.channels.is_empty()
+    }
+
+    /// Opens the channel prompt, filled in with the current channels.
+    pub fn edit_channels(&mut self) {
+        self.input = Some(self.channels.join(", "));
+    }
+
+    /// Closes the channel prompt, returning the channels typed into it.
+    pub fn take_input(&mut self) -> Vec<String> {
+        parse_channels(&self.input.take().unwrap_or_default())
+    }
+
+    /// Adds a notification, keeping the selection on the newest one if it was already there.
+    pub fn push(&mut self, notification: ChannelNotification) {
+        let following = self.selected + 1 >= self.notifications.len();
+        self.notifications.push(notification);
+        if self.notifications.len() > MAX_NOTIFICATIONS {
+            self.notifications.remove(0);
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if following {
+            self.select_last();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.notifications.clear();
+        self.selected = 0;
+    }
+
+    pub fn selected_notification(&self) -> Option<&ChannelNotification> {
+        self.notifications.get(self.selected)
+    }
+
+    pub fn move_down(&mut self, count: usize) {
+        let last = self.notifications.len().saturating_sub(1);
+        self.selected = (self.selected + count).min(last);
+    }
+
+    pub fn move_up(&mut self, count: usize) {
+        self.selected = self.selected.saturating_sub(count);
+    }
+
+    pub fn select_last(&mut self) {
+        self.selected = self.notifications.len().saturating_sub(1);
+    }
+}
+
+/// Channel names separated by commas or whitespace, without duplicates.
+pub fn parse_channels(input: &str) -> Vec<String> {
+    let mut channels: Vec<String> = Vec::new();
+    for channel in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        if !channel.is_empty() && !channels.iter().any(|c| c == channel) {
+            channels.push(channel.to_string());
+        }
+    }
+    channels
+}
@@ -1,9 +1,33 @@
 use serde::{Deserialize, Serialize};
 
+use super::{PoolConfig, TlsConfig};
+
+/// Number of rows fetched per query when `row_limit` is not configured.
+pub const DEFAULT_ROW_LIMIT: usize = 1000;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DatabaseConfig {
     pub url: Option<String>,
     pub db_type: Option<DatabaseType>,
+    /// Maximum number of rows streamed for a single query before the result is truncated.
+    pub row_limit: Option<usize>,
+    /// Schema searched first for unqualified names (the database, for MySQL).
+    #[serde(default)]
+    pub default_schema: Option<String>,
+    #[serde(default)]
+    pub read_only: bool,
+    /// Server-side limits, e.g. `"30s"`, set on every new PostgreSQL connection.
+    #[serde(default)]
+    pub statement_timeout: Option<String>,
+    #[serde(default)]
+    pub lock_timeout: Option<String>,
+    /// Statements run, in order, on every new connection, e.g. `SET ROLE reporting`.
+    #[serde(default)]
+    pub init_sql: Vec<String>,
+    #[serde(flatten)]
+    pub tls: TlsConfig,
+    #[serde(default)]
+    pub pool: PoolConfig,
 }
 
 impl DatabaseConfig {
@@ -17,6 +41,50 @@ impl DatabaseConfig {
         self
     }
 
+    pub fn with_row_limit(mut self, row_limit: usize) -> Self {
+        self.row_limit = Some(row_limit);
+        self
+    }
+
+    pub fn with_default_schema(mut self, schema: String) -> Self {
+        self.default_schema = Some(schema);
+        self
+    }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    pub fn with_statement_timeout(mut self, timeout: String) -> Self {
+        self.statement_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_lock_timeout(mut self, timeout: String) -> Self {
+        self.lock_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_init_sql(mut self, statements: Vec<String>) -> Self {
+        self.init_sql = statements;
+        self
+    }
+
+    pub fn with_pool(mut self, pool: PoolConfig) -> Self {
+        self.pool = pool;
+        self
+    }
+
+    pub fn get_row_limit(&self) -> usize {
+        self.row_limit.unwrap_or(DEFAULT_ROW_LIMIT).max(1)
+    }
+
     pub fn detect_type(&self) -> Option<DatabaseType> {
         if let Some(url) = &self.url {
             Self::detect_type_from_url(url)
@@ -30,7 +98,7 @@ impl DatabaseConfig {
             Some(DatabaseType::PostgreSQL)
         } else if url.starts_with("mysql://") {
             Some(DatabaseType::MySQL)
-        } else if url.starts_with("sqlite://") {
+        } else if url.starts_with("sqlite:") {
             Some(DatabaseType::SQLite)
         } else {
             None
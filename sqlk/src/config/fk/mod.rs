@@ -5,6 +5,10 @@ use std::collections::HashMap;
 pub struct ForeignKeyConfig {
     pub enabled: bool,
     pub manual_mapping: HashMap<String, String>,
+    /// Schemas scanned for foreign keys. Empty means the connection's search path
+    /// (`main` for SQLite, the connected database for MySQL).
+    #[serde(default)]
+    pub schemas: Vec<String>,
 }
 
 impl Default for ForeignKeyConfig {
@@ -12,6 +16,7 @@ impl Default for ForeignKeyConfig {
         Self {
             enabled: true,
             manual_mapping: HashMap::new(),
+            schemas: Vec::new(),
         }
     }
 }
@@ -31,6 +36,11 @@ impl ForeignKeyConfig {
         self
     }
 
+    pub fn with_schemas(mut self, schemas: Vec<String>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
     pub fn add_mapping(&mut self, from: String, to: String) {
         self.manual_mapping.insert(from, to);
     }
@@ -0,0 +1,277 @@
+use anyhow::{Result, anyhow};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Environment variables libpq reads, with the connection keyword each one supplies.
+const ENVIRONMENT: &[(&str, &str)] = &[
+    ("PGHOST", "host"),
+    ("PGHOSTADDR", "hostaddr"),
+    ("PGPORT", "port"),
+    ("PGDATABASE", "dbname"),
+    ("PGUSER", "user"),
+    ("PGPASSWORD", "password"),
+    ("PGSSLMODE", "sslmode"),
+    ("PGSSLROOTCERT", "sslrootcert"),
+    ("PGSSLCERT", "sslcert"),
+    ("PGSSLKEY", "sslkey"),
+    ("PGAPPNAME", "application_name"),
+    ("PGOPTIONS", "options"),
+];
+
+/// Keywords passed through to the connection URL as query parameters.
+const URL_PARAMETERS: &[&str] = &[
+    "sslmode",
+    "sslrootcert",
+    "sslcert",
+    "sslkey",
+    "application_name",
+    "options",
+];
+
+/// Socket directories libpq builds commonly default to, checked in order.
+const SOCKET_DIRECTORIES: &[&str] = &["/var/run/postgresql", "/private/tmp", "/tmp"];
+
+/// Connection settings gathered the way libpq gathers them for `psql`: the `PGSERVICE`
+/// entry from `pg_service.conf` first, then `PG*` environment variables for anything the
+/// service left unset, then defaults, with the password looked up in `.pgpass` last.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LibpqSettings {
+    params: BTreeMap<String, String>,
+}
+
+impl LibpqSettings {
+    /// Settings from the current environment, or `None` when neither a service nor any
+    /// connection variable is set.
+    pub fn from_env() -> Result<Option<Self>> {
+        let service = std::env::var("PGSERVICE").ok();
+        let mut params = match &service {
+            Some(service) => find_service(service)?,
+            None => BTreeMap::new(),
+        };
+        let mut from_environment = false;
+        for (variable, keyword) in ENVIRONMENT {
+            if let Ok(value) = std::env::var(variable) {
+                from_environment = true;
+                params.entry(keyword.to_string()).or_insert(value);
+            }
+        }
+        if service.is_none() && !from_environment {
+            return Ok(None);
+        }
+
+        let mut settings = Self { params };
+        settings.apply_defaults();
+        if !settings.params.contains_key("password")
+            && let Some(password) = settings.pgpass_password()
+        {
+            settings.params.insert("password".to_string(), password);
+        }
+        Ok(Some(settings))
+    }
+
+    pub fn get(&self, keyword: &str) -> Option<&str> {
+        self.params.get(keyword).map(String::as_str)
+    }
+
+    /// A `postgres://` URL carrying every gathered setting. Socket directories go in the
+    /// `host` query parameter since they cannot be written as a URL host.
+    pub fn to_url(&self) -> String {
+        let host = self
+            .get("hostaddr")
+            .or(self.get("host"))
+            .unwrap_or("localhost");
+        let mut url = String::from("postgres://");
+        if let Some(user) = self.get("user") {
+            url.push_str(&percent_encode(user));
+            if let Some(password) = self.get("password") {
+                url.push(':');
+                url.push_str(&percent_encode(password));
+            }
+            url.push('@');
+        }
+
+        let mut query = Vec::new();
+        if host.starts_with('/') {
+            url.push_str("localhost");
+            query.push(format!("host={}", percent_encode(host)));
+        } else if host.contains(':') {
+            url.push_str(&format!("[{}]", host));
+        } else {
+            url.push_str(host);
+        }
+        if let Some(port) = self.get("port") {
+            url.push(':');
+            url.push_str(port);
+        }
+        if let Some(dbname) = self.get("dbname") {
+            url.push('/');
+            url.push_str(&percent_encode(dbname));
+        }
+
+        for keyword in URL_PARAMETERS {
+            if let Some(value) = self.get(keyword) {
+                query.push(format!("{}={}", keyword, percent_encode(value)));
+            }
+        }
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(&query.join("&"));
+        }
+        url
+    }
+
+    /// libpq's defaults: the first local socket found, port 5432, the login name as the
+    /// user and the user name as the database.
+    fn apply_defaults(&mut self) {
+        let port = self.get("port").unwrap_or("5432").to_string();
+        if !self.params.contains_key("host") && !self.params.contains_key("hostaddr") {
+            let socket = format!(".s.PGSQL.{}", port);
+            let host = SOCKET_DIRECTORIES
+                .iter()
+                .find(|dir| Path::new(dir).join(&socket).exists())
+                .copied()
+                .unwrap_or("localhost");
+            self.params.insert("host".to_string(), host.to_string());
+        }
+        self.params.entry("port".to_string()).or_insert(port);
+        if !self.params.contains_key("user")
+            && let Ok(user) = std::env::var("USER").or_else(|_| std::env::var("LOGNAME"))
+        {
+            self.params.insert("user".to_string(), user);
+        }
+        if !self.params.contains_key("dbname")
+            && let Some(user) = self.params.get("user").cloned()
+        {
+            self.params.insert("dbname".to_string(), user);
+        }
+    }
+
+    /// The first `.pgpass` line matching `host:port:database:user`, where `*` matches
+    /// anything and a socket connection counts as `localhost`.
+    fn pgpass_password(&self) -> Option<String> {
+        let path = std::env::var_os("PGPASSFILE")
+            .map(PathBuf::from)
+            .or_else(|| dirs::home_dir().map(|home| home.join(".pgpass")))?;
+        if !is_private(&path) {
+            return None;
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+
+        let host = self.get("host").unwrap_or("localhost");
+        let host = if host.starts_with('/') {
+            "localhost"
+        } else {
+            host
+        };
+        let wanted = [
+            host,
+            self.get("port").unwrap_or("5432"),
+            self.get("dbname").unwrap_or_default(),
+            self.get("user").unwrap_or_default(),
+        ];
+        content
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'))
+            .find_map(|line| {
+                let fields = split_pgpass_line(line);
+                if fields.len() < 5 {
+                    return None;
+                }
+                let matches = fields
+                    .iter()
+                    .zip(wanted)
+                    .all(|(field, wanted)| field == "*" || field == wanted);
+                matches.then(|| fields[4..].join(":"))
+            })
+    }
+}
+
+/// The key/value pairs of `[service]` from the user's service file, or the system-wide one
+/// when the user's does not define it.
+fn find_service(service: &str) -> Result<BTreeMap<String, String>> {
+    let user_file = std::env::var_os("PGSERVICEFILE")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".pg_service.conf")));
+    let system_files = match std::env::var_os("PGSYSCONFDIR") {
+        Some(dir) => vec![PathBuf::from(dir).join("pg_service.conf")],
+        None => vec![
+            PathBuf::from("/etc/postgresql-common/pg_service.conf"),
+            PathBuf::from("/etc/pg_service.conf"),
+        ],
+    };
+
+    user_file
+        .into_iter()
+        .chain(system_files)
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .find_map(|content| parse_service_file(&content, service))
+        .ok_or_else(|| anyhow!("Definition of service \"{}\" not found", service))
+}
+
+fn parse_service_file(content: &str, service: &str) -> Option<BTreeMap<String, String>> {
+    let mut params = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            if params.is_some() {
+                break;
+            }
+            if section.trim() == service {
+                params = Some(BTreeMap::new());
+            }
+        } else if let Some(params) = &mut params
+            && let Some((key, value)) = line.split_once('=')
+        {
+            params.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    params
+}
+
+/// Splits a `.pgpass` line on unescaped colons, resolving `\:` and `\\`.
+fn split_pgpass_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    fields.last_mut().unwrap().push(escaped);
+                }
+            }
+            ':' => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// libpq ignores a password file that group or others can read.
+#[cfg(unix)]
+fn is_private(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.permissions().mode() & 0o077 == 0)
+}
+
+#[cfg(not(unix))]
+fn is_private(path: &Path) -> bool {
+    path.exists()
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
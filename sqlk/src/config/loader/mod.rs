@@ -1,3 +1,4 @@
+use super::libpq::LibpqSettings;
 use super::parser::DatabaseUrlParser;
 use super::{Config, DatabaseConfig};
 use anyhow::Result;
@@ -33,7 +34,7 @@ impl ConfigLoader {
             config.env_file = env_file.to_path_buf();
         }
 
-        config.database = self.load_database_config(env_file)?;
+        config.database = self.load_database_config(env_file, config.database.clone())?;
 
         Ok(config)
     }
@@ -54,8 +55,15 @@ impl ConfigLoader {
         Ok(config)
     }
 
-    fn load_database_config(&self, env_file: &Path) -> Result<DatabaseConfig> {
-        let mut database_config = DatabaseConfig::new();
+    fn load_database_config(
+        &self,
+        env_file: &Path,
+        file_config: DatabaseConfig,
+    ) -> Result<DatabaseConfig> {
+        let mut database_config = DatabaseConfig {
+            url: None,
+            ..file_config.clone()
+        };
 
         if env_file.exists()
             && let Some(url) = self.load_database_url_from_file(env_file)? {
@@ -67,6 +75,18 @@ impl ConfigLoader {
                 database_config = database_config.with_url(url);
             }
 
+        if database_config.url.is_none()
+            && let Some(url) = file_config.url
+        {
+            database_config = database_config.with_url(url);
+        }
+
+        if database_config.url.is_none()
+            && let Some(settings) = LibpqSettings::from_env()?
+        {
+            database_config = database_config.with_url(settings.to_url());
+        }
+
         Ok(database_config)
     }
 
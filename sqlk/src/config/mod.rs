@@ -1,16 +1,25 @@
 pub mod db;
 pub mod fk;
+pub mod libpq;
 pub mod loader;
 pub mod matrix;
 pub mod parser;
+pub mod pool;
+pub mod profile;
+pub mod tls;
 
 pub use db::{DatabaseConfig, DatabaseType};
 pub use fk::ForeignKeyConfig;
+pub use libpq::LibpqSettings;
 pub use loader::ConfigLoader;
 pub use matrix::MatrixConfig;
+pub use pool::PoolConfig;
+pub use profile::ConnectionProfile;
+pub use tls::{SslMode, TlsConfig};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,7 +28,16 @@ pub struct Config {
     pub matrix: MatrixConfig,
     pub foreign_keys: ForeignKeyConfig,
     pub database: DatabaseConfig,
-    pub toast_level: String
+    pub toast_level: String,
+    /// Named connections, listed by name in the profile picker.
+    #[serde(default)]
+    pub connections: BTreeMap<String, ConnectionProfile>,
+    /// The profile `database` was last pointed at, if any.
+    #[serde(skip)]
+    pub active_profile: Option<String>,
+    /// `database` as loaded, before any profile was applied to it.
+    #[serde(skip)]
+    base_database: Option<DatabaseConfig>,
 }
 
 
@@ -30,7 +48,10 @@ impl Default for Config {
             matrix: MatrixConfig::default(),
             foreign_keys: ForeignKeyConfig::default(),
             database: DatabaseConfig::default(),
-            toast_level: String::from("ERROR")
+            toast_level: String::from("ERROR"),
+            connections: BTreeMap::new(),
+            active_profile: None,
+            base_database: None,
         }
     }
 }
@@ -44,6 +65,27 @@ impl Config {
         ConfigLoader::new().save(self)
     }
 
+    /// Switches `database` to the named `[connections.<name>]` profile.
+    pub fn use_profile(&mut self, name: &str) -> Result<()> {
+        let profile = self
+            .connections
+            .get(name)
+            .ok_or_else(|| anyhow!("No connection profile named '{}'", name))?;
+        let base = self
+            .base_database
+            .get_or_insert_with(|| self.database.clone())
+            .clone();
+        self.database = profile.apply(base);
+        self.active_profile = Some(name.to_string());
+        Ok(())
+    }
+
+    pub fn active_connection(&self) -> Option<&ConnectionProfile> {
+        self.active_profile
+            .as_ref()
+            .and_then(|name| self.connections.get(name))
+    }
+
     pub fn get_database_url(&self) -> Option<&str> {
         self.database.url.as_deref()
     }
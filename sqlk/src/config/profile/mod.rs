@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use super::{DatabaseConfig, TlsConfig};
+
+/// A named connection from a `[connections.<name>]` table in the config file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub url: String,
+    /// Schema searched first for unqualified names (the database, for MySQL).
+    #[serde(default)]
+    pub default_schema: Option<String>,
+    #[serde(default)]
+    pub read_only: bool,
+    /// Color the profile name is shown in, e.g. `"red"` or `"#ff8800"`.
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Overrides `statement_timeout` from `[database]`.
+    #[serde(default)]
+    pub statement_timeout: Option<String>,
+    /// Overrides `lock_timeout` from `[database]`.
+    #[serde(default)]
+    pub lock_timeout: Option<String>,
+    /// Replaces `init_sql` from `[database]`.
+    #[serde(default)]
+    pub init_sql: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub tls: TlsConfig,
+}
+
+impl ConnectionProfile {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            default_schema: None,
+            read_only: false,
+            color: None,
+            statement_timeout: None,
+            lock_timeout: None,
+            init_sql: None,
+            tls: TlsConfig::default(),
+        }
+    }
+
+    pub fn with_default_schema(mut self, schema: String) -> Self {
+        self.default_schema = Some(schema);
+        self
+    }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn with_color(mut self, color: String) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_statement_timeout(mut self, timeout: String) -> Self {
+        self.statement_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_lock_timeout(mut self, timeout: String) -> Self {
+        self.lock_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_init_sql(mut self, statements: Vec<String>) -> Self {
+        self.init_sql = Some(statements);
+        self
+    }
+
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// Points `database` at this profile, keeping settings the profile does not define.
+    pub fn apply(&self, database: DatabaseConfig) -> DatabaseConfig {
+        let mut database = database.with_url(self.url.clone());
+        database.default_schema = self.default_schema.clone();
+        database.read_only = self.read_only;
+        if let Some(timeout) = &self.statement_timeout {
+            database.statement_timeout = Some(timeout.clone());
+        }
+        if let Some(timeout) = &self.lock_timeout {
+            database.lock_timeout = Some(timeout.clone());
+        }
+        if let Some(statements) = &self.init_sql {
+            database.init_sql = statements.clone();
+        }
+        database.tls = database.tls.merge(&self.tls);
+        database
+    }
+}
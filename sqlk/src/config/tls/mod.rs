@@ -0,0 +1,102 @@
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// How hard to insist on TLS, using libpq's mode names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SslMode {
+    Disable,
+    Allow,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl SslMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SslMode::Disable => "disable",
+            SslMode::Allow => "allow",
+            SslMode::Prefer => "prefer",
+            SslMode::Require => "require",
+            SslMode::VerifyCa => "verify-ca",
+            SslMode::VerifyFull => "verify-full",
+        }
+    }
+}
+
+/// TLS settings applied on top of whatever the connection URL asks for.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TlsConfig {
+    #[serde(default)]
+    pub sslmode: Option<SslMode>,
+    /// CA certificate the server's certificate must chain to.
+    #[serde(default)]
+    pub sslrootcert: Option<PathBuf>,
+    /// Client certificate and key for servers that require certificate authentication.
+    #[serde(default)]
+    pub sslcert: Option<PathBuf>,
+    #[serde(default)]
+    pub sslkey: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_sslmode(mut self, sslmode: SslMode) -> Self {
+        self.sslmode = Some(sslmode);
+        self
+    }
+
+    pub fn with_sslrootcert(mut self, path: PathBuf) -> Self {
+        self.sslrootcert = Some(path);
+        self
+    }
+
+    pub fn with_client_cert(mut self, cert: PathBuf, key: PathBuf) -> Self {
+        self.sslcert = Some(cert);
+        self.sslkey = Some(key);
+        self
+    }
+
+    /// These settings, with any set in `overrides` taking their place.
+    pub fn merge(&self, overrides: &TlsConfig) -> TlsConfig {
+        TlsConfig {
+            sslmode: overrides.sslmode.or(self.sslmode),
+            sslrootcert: overrides.sslrootcert.clone().or(self.sslrootcert.clone()),
+            sslcert: overrides.sslcert.clone().or(self.sslcert.clone()),
+            sslkey: overrides.sslkey.clone().or(self.sslkey.clone()),
+        }
+    }
+
+    /// Checks the certificate files up front, since the driver reports a missing one as a
+    /// bare I/O error once the handshake has already started.
+    pub fn validate(&self) -> Result<()> {
+        if self.sslcert.is_some() != self.sslkey.is_some() {
+            return Err(anyhow!(
+                "TLS configuration error: sslcert and sslkey must be set together"
+            ));
+        }
+        for (setting, path) in [
+            ("sslrootcert", &self.sslrootcert),
+            ("sslcert", &self.sslcert),
+            ("sslkey", &self.sslkey),
+        ] {
+            if let Some(path) = path
+                && let Err(e) = std::fs::metadata(path)
+            {
+                return Err(anyhow!(
+                    "TLS configuration error: cannot read {} '{}': {}",
+                    setting,
+                    path.display(),
+                    e
+                ));
+            }
+        }
+        Ok(())
+    }
+}
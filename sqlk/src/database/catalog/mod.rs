@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct SchemaInfo {
+    pub name: String,
+    pub tables: Vec<TableInfo>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableKind {
+    Table,
+    View,
+    MaterializedView,
+    ForeignTable,
+}
+
+impl TableKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TableKind::Table => "table",
+            TableKind::View => "view",
+            TableKind::MaterializedView => "materialized view",
+            TableKind::ForeignTable => "foreign table",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableInfo {
+    pub schema: String,
+    pub name: String,
+    pub kind: TableKind,
+    /// Row count from planner statistics; `None` when the backend has none.
+    pub estimated_rows: Option<i64>,
+    pub columns: Vec<ColumnInfo>,
+    pub indexes: Vec<IndexInfo>,
+    pub constraints: Vec<ConstraintInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexInfo {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub unique: bool,
+    pub primary: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstraintInfo {
+    pub name: String,
+    /// SQL-like description, e.g. `FOREIGN KEY (user_id) REFERENCES users(id)`.
+    pub definition: String,
+}
+
+/// Collects catalog rows, which backends fetch per object type, into schemas and tables.
+#[derive(Debug, Default)]
+pub struct CatalogBuilder {
+    schemas: Vec<SchemaInfo>,
+    tables: HashMap<(String, String), (usize, usize)>,
+}
+
+impl CatalogBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_schema(&mut self, name: &str) {
+        if !self.schemas.iter().any(|schema| schema.name == name) {
+            self.schemas.push(SchemaInfo {
+                name: name.to_string(),
+                tables: Vec::new(),
+            });
+        }
+    }
+
+    pub fn add_table(
+        &mut self,
+        schema: &str,
+        name: &str,
+        kind: TableKind,
+        estimated_rows: Option<i64>,
+    ) {
+        self.add_schema(schema);
+        let Some(schema_idx) = self.schemas.iter().position(|info| info.name == schema) else {
+            return;
+        };
+        let tables = &mut self.schemas[schema_idx].tables;
+        self.tables.insert(
+            (schema.to_string(), name.to_string()),
+            (schema_idx, tables.len()),
+        );
+        tables.push(TableInfo {
+            schema: schema.to_string(),
+            name: name.to_string(),
+            kind,
+            estimated_rows,
+            columns: Vec::new(),
+            indexes: Vec::new(),
+            constraints: Vec::new(),
+        });
+    }
+
+    /// The table added as `schema.name`; rows for unknown tables are meant to be dropped.
+    pub fn table_mut(&mut self, schema: &str, name: &str) -> Option<&mut TableInfo> {
+        let (schema_idx, table_idx) = *self.tables.get(&(schema.to_string(), name.to_string()))?;
+        Some(&mut self.schemas[schema_idx].tables[table_idx])
+    }
+
+    pub fn build(self) -> Vec<SchemaInfo> {
+        self.schemas
+    }
+}
@@ -1,5 +1,338 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::database::*;
+use crate::query_parser::QueryAnalyzer;
 
 pub trait ForeignKeyAnalyzer {
     fn analyze_foreign_keys(&self) -> anyhow::Result<SchemaCache>;
 }
+
+/// A table column, optionally schema-qualified. Names are stored lowercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnRef {
+    pub schema: Option<String>,
+    pub table: String,
+    pub column: String,
+}
+
+impl ColumnRef {
+    pub fn new(schema: Option<&str>, table: &str, column: &str) -> Self {
+        Self {
+            schema: schema.map(str::to_lowercase),
+            table: table.to_lowercase(),
+            column: column.to_lowercase(),
+        }
+    }
+
+    /// Whether this is `column` of `schema.table`; an unqualified ref matches any schema.
+    pub fn refers_to(&self, schema: &str, table: &str, column: &str) -> bool {
+        self.schema
+            .as_ref()
+            .is_none_or(|own| own.eq_ignore_ascii_case(schema))
+            && self.table.eq_ignore_ascii_case(table)
+            && self.column.eq_ignore_ascii_case(column)
+    }
+
+    /// Attributes every header to `table`, as for a `SELECT *` from a single table.
+    pub fn for_table(schema: &str, table: &str, headers: &[String]) -> Vec<Option<Self>> {
+        headers
+            .iter()
+            .map(|header| Some(Self::new(Some(schema), table, header)))
+            .collect()
+    }
+}
+
+impl ForeignKeyInfo {
+    pub fn is_composite(&self) -> bool {
+        self.columns.len() > 1
+    }
+
+    /// Short form of the referencing side, e.g. `orders.user_id`.
+    pub fn source_label(&self) -> String {
+        column_label(&self.table_name, &self.columns)
+    }
+
+    /// Short form of the referenced side, e.g. `users.id`.
+    pub fn referenced_label(&self) -> String {
+        column_label(&self.referenced_table, &self.referenced_columns)
+    }
+
+    pub fn target_display(&self) -> String {
+        format!(
+            "{}.{}({})",
+            self.referenced_schema,
+            self.referenced_table,
+            self.referenced_columns.join(", ")
+        )
+    }
+}
+
+fn column_label(table: &str, columns: &[String]) -> String {
+    match columns {
+        [column] => format!("{}.{}", table, column),
+        _ => format!("{}({})", table, columns.join(", ")),
+    }
+}
+
+type ColumnKey = (String, String, String);
+
+/// A (schema, table, column) triple as spelled in the database catalog.
+pub type CatalogColumn = (String, String, String);
+
+/// Foreign key metadata keyed by the referencing (schema, table, column).
+#[derive(Debug, Clone, Default)]
+pub struct SchemaCache {
+    foreign_keys: Vec<ForeignKeyInfo>,
+    by_column: HashMap<ColumnKey, usize>,
+    referenced_columns: HashSet<ColumnKey>,
+    warnings: Vec<String>,
+}
+
+impl SchemaCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `fk_info` under each of its columns. A column that is part of several
+    /// constraints keeps the first one inserted.
+    pub fn insert(&mut self, fk_info: ForeignKeyInfo) {
+        self.insert_with_precedence(fk_info, false);
+    }
+
+    fn insert_with_precedence(&mut self, fk_info: ForeignKeyInfo, overrides: bool) {
+        for column in &fk_info.referenced_columns {
+            self.referenced_columns.insert((
+                fk_info.referenced_schema.to_lowercase(),
+                fk_info.referenced_table.to_lowercase(),
+                column.to_lowercase(),
+            ));
+        }
+        let idx = self.foreign_keys.len();
+        for column in &fk_info.columns {
+            let key = (
+                fk_info.schema.to_lowercase(),
+                fk_info.table_name.to_lowercase(),
+                column.to_lowercase(),
+            );
+            if overrides {
+                self.by_column.insert(key, idx);
+            } else {
+                self.by_column.entry(key).or_insert(idx);
+            }
+        }
+        self.foreign_keys.push(fk_info);
+    }
+
+    /// Layers `[foreign_keys.manual_mapping]` over the discovered constraints, replacing
+    /// them on any column both cover. `columns` is every column the connection can see and
+    /// is used to resolve bare column names and to validate targets; entries that do not
+    /// resolve are skipped and reported through [`SchemaCache::warnings`].
+    pub fn apply_manual_mapping(
+        &mut self,
+        mapping: &HashMap<String, String>,
+        columns: &[CatalogColumn],
+    ) {
+        // Bare column names go first so a `table.column` entry wins where both apply.
+        let mut entries: Vec<(&String, &String)> = mapping.iter().collect();
+        entries.sort_by(|(a, _), (b, _)| (a.contains('.'), a).cmp(&(b.contains('.'), b)));
+
+        for (from, to) in entries {
+            match resolve_manual_mapping(from, to, columns) {
+                Ok(foreign_keys) => {
+                    for fk_info in foreign_keys {
+                        self.insert_with_precedence(fk_info, true);
+                    }
+                }
+                Err(e) => self
+                    .warnings
+                    .push(format!("manual_mapping {} = \"{}\": {}", from, to, e)),
+            }
+        }
+    }
+
+    /// Problems found while loading foreign key metadata.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.foreign_keys.is_empty()
+    }
+
+    /// Finds the foreign key on `column`. Without a schema, the table name must be unambiguous.
+    pub fn get(&self, column: &ColumnRef) -> Option<&ForeignKeyInfo> {
+        if let Some(schema) = &column.schema {
+            return self
+                .by_column
+                .get(&(schema.clone(), column.table.clone(), column.column.clone()))
+                .map(|idx| &self.foreign_keys[*idx]);
+        }
+
+        let mut matches = self
+            .by_column
+            .iter()
+            .filter(|((_, table, name), _)| *table == column.table && *name == column.column)
+            .map(|(_, idx)| &self.foreign_keys[*idx]);
+        let first = matches.next();
+        if matches.next().is_some() {
+            None
+        } else {
+            first
+        }
+    }
+
+    /// Whether `column` takes part in any known foreign key, on either side.
+    pub fn knows_column(&self, column: &ColumnRef) -> bool {
+        let matches = |(schema, table, name): &ColumnKey| {
+            column.schema.as_ref().is_none_or(|s| s == schema)
+                && *table == column.table
+                && *name == column.column
+        };
+        self.by_column.keys().any(matches) || self.referenced_columns.iter().any(matches)
+    }
+
+    /// Every foreign key that points at `table`.
+    pub fn referencing(&self, schema: Option<&str>, table: &str) -> Vec<&ForeignKeyInfo> {
+        // Constraints fully overridden by a manual mapping are no longer reachable.
+        let live: HashSet<usize> = self.by_column.values().copied().collect();
+        self.foreign_keys
+            .iter()
+            .enumerate()
+            .filter(|(idx, fk_info)| {
+                live.contains(idx)
+                    && schema.is_none_or(|schema| {
+                        schema.eq_ignore_ascii_case(&fk_info.referenced_schema)
+                    })
+                    && table.eq_ignore_ascii_case(&fk_info.referenced_table)
+            })
+            .map(|(_, fk_info)| fk_info)
+            .collect()
+    }
+}
+
+/// Splits `[schema.]table.column` (or a bare `column`) into its parts.
+fn split_column_name(name: &str) -> Option<(Option<&str>, Option<&str>, &str)> {
+    let parts: Vec<&str> = name.split('.').map(str::trim).collect();
+    if parts.iter().any(|part| part.is_empty()) {
+        return None;
+    }
+    match parts[..] {
+        [column] => Some((None, None, column)),
+        [table, column] => Some((None, Some(table), column)),
+        [schema, table, column] => Some((Some(schema), Some(table), column)),
+        _ => None,
+    }
+}
+
+fn catalog_matches(
+    (schema, table, column): &CatalogColumn,
+    want_schema: Option<&str>,
+    want_table: Option<&str>,
+    want_column: Option<&str>,
+) -> bool {
+    want_schema.is_none_or(|want| want.eq_ignore_ascii_case(schema))
+        && want_table.is_none_or(|want| want.eq_ignore_ascii_case(table))
+        && want_column.is_none_or(|want| want.eq_ignore_ascii_case(column))
+}
+
+/// Turns one manual mapping entry into a foreign key per matching source column.
+fn resolve_manual_mapping(
+    from: &str,
+    to: &str,
+    columns: &[CatalogColumn],
+) -> Result<Vec<ForeignKeyInfo>, String> {
+    let Some((target_schema, Some(target_table), target_column)) = split_column_name(to) else {
+        return Err("target must be table.column or schema.table.column".to_string());
+    };
+    let Some((source_schema, source_table, source_column)) = split_column_name(from) else {
+        return Err("key must be column, table.column or schema.table.column".to_string());
+    };
+
+    let mut target_tables = columns
+        .iter()
+        .filter(|entry| catalog_matches(entry, target_schema, Some(target_table), None))
+        .map(|(schema, table, _)| (schema.as_str(), table.as_str()))
+        .collect::<HashSet<_>>()
+        .into_iter();
+    let (schema, table) = match (target_tables.next(), target_tables.next()) {
+        (None, _) => return Err(format!("unknown table {}", target_table)),
+        (Some(found), None) => found,
+        (Some(_), Some(_)) => {
+            return Err(format!(
+                "table {} exists in several schemas, qualify it as schema.table.column",
+                target_table
+            ));
+        }
+    };
+    let Some(target) = columns
+        .iter()
+        .find(|entry| catalog_matches(entry, Some(schema), Some(table), Some(target_column)))
+    else {
+        return Err(format!("unknown column {}.{}", table, target_column));
+    };
+
+    let foreign_keys: Vec<ForeignKeyInfo> = columns
+        .iter()
+        .filter(|entry| {
+            catalog_matches(entry, source_schema, source_table, Some(source_column))
+                && *entry != target
+        })
+        .map(|(schema, table, column)| ForeignKeyInfo {
+            constraint_name: format!("manual:{}", from),
+            schema: schema.clone(),
+            table_name: table.clone(),
+            columns: vec![column.clone()],
+            referenced_schema: target.0.clone(),
+            referenced_table: target.1.clone(),
+            referenced_columns: vec![target.2.clone()],
+        })
+        .collect();
+
+    if foreign_keys.is_empty() {
+        return Err(match source_table {
+            Some(table)
+                if !columns
+                    .iter()
+                    .any(|entry| catalog_matches(entry, source_schema, Some(table), None)) =>
+            {
+                format!("unknown table {}", table)
+            }
+            Some(table) => format!("unknown column {}.{}", table, source_column),
+            None => format!("no table has a column named {}", source_column),
+        });
+    }
+    Ok(foreign_keys)
+}
+
+/// Best-effort mapping of result headers to source tables from the query text, for
+/// backends that do not report column origins.
+pub fn infer_column_sources(
+    schema_cache: &SchemaCache,
+    query: &str,
+    headers: &[String],
+) -> Vec<Option<ColumnRef>> {
+    let table_names = QueryAnalyzer::new().extract_table_names(query);
+    let tables: Vec<(Option<&str>, &str)> = table_names
+        .iter()
+        .map(|name| match name.split_once('.') {
+            Some((schema, table)) => (Some(schema), table),
+            None => (None, name.as_str()),
+        })
+        .collect();
+
+    headers
+        .iter()
+        .map(|header| {
+            let mut candidates = tables
+                .iter()
+                .map(|(schema, table)| ColumnRef::new(*schema, table, header))
+                .filter(|column| tables.len() == 1 || schema_cache.knows_column(column));
+            let first = candidates.next();
+            if candidates.next().is_some() {
+                None
+            } else {
+                first
+            }
+        })
+        .collect()
+}
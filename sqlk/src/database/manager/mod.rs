@@ -1,43 +1,283 @@
 use crate::{
-    config::Config,
-    database::{ForeignKeyInfo, QueryResult},
+    channel_monitor::ChannelNotification,
+    config::{Config, DatabaseType},
+    database::{ColumnRef, ForeignKeyInfo, QueryParam, QueryResult, SchemaInfo, Value},
+    query_parser::SafetyChecker,
+    query_plan::ExplainMode,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
 use std::sync::Arc;
 
 #[async_trait]
 pub trait DatabaseClient: Send + Sync {
-    async fn execute_query(&self, query: &str) -> Result<QueryResult>;
-    async fn lookup_foreign_key(&self, column_name: &str, value: &str) -> Result<QueryResult>;
-    fn get_foreign_key_info(&self, column_name: &str) -> Result<&ForeignKeyInfo>;
+    /// Streams at most `row_limit` rows, flagging the result as truncated if more remain.
+    /// `params` are bound to the query's `$1`, `:name` and `@name` placeholders.
+    async fn execute_query(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        row_limit: usize,
+    ) -> Result<QueryResult>;
+    /// The next `row_limit` rows of the last query's result, if it was truncated and is
+    /// still open.
+    async fn fetch_more(&self, _row_limit: usize) -> Result<QueryResult> {
+        Err(anyhow::anyhow!(
+            "The rest of this result is no longer available"
+        ))
+    }
+    /// Selects up to `limit` rows of `schema.table` whose `columns` equal `values`.
+    async fn fetch_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+        limit: usize,
+    ) -> Result<QueryResult>;
+    async fn count_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+    ) -> Result<i64>;
+    fn get_foreign_key_info(&self, column: &ColumnRef) -> Option<&ForeignKeyInfo>;
+    fn get_referencing_foreign_keys(
+        &self,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Vec<&ForeignKeyInfo>;
+    /// Problems found while loading foreign key metadata, such as unresolvable manual mappings.
+    fn foreign_key_warnings(&self) -> &[String];
+    /// Schemas with their tables, columns, indexes and constraints, for the schema browser.
+    async fn introspect_schema(&self) -> Result<Vec<SchemaInfo>>;
+    /// A `SELECT *` of the first `limit` rows of `schema.table`, quoted for this backend.
+    fn preview_query(&self, schema: &str, table: &str, limit: usize) -> String;
+    /// True between a `BEGIN` and its `COMMIT` or `ROLLBACK`, while a connection is pinned.
+    fn in_transaction(&self) -> bool;
+    /// Asks the server to stop the statements this client is running at the time of the call.
+    /// The returned future can finish after the tasks running them have been dropped.
+    fn cancel_query(&self) -> BoxFuture<'static, Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+    /// The `EXPLAIN (FORMAT JSON)` output for `query`. With [`ExplainMode::Analyze`] the
+    /// statement runs, inside a transaction that is rolled back.
+    async fn explain(
+        &self,
+        _query: &str,
+        _params: &[QueryParam],
+        _mode: ExplainMode,
+    ) -> Result<serde_json::Value> {
+        Err(anyhow::anyhow!(
+            "Query plans are only available for PostgreSQL"
+        ))
+    }
+    /// Subscribes to `channels` on a connection outside the pool, yielding each notification
+    /// as it arrives until the stream is dropped.
+    async fn listen(
+        &self,
+        _channels: &[String],
+    ) -> Result<BoxStream<'static, Result<ChannelNotification>>> {
+        Err(anyhow::anyhow!(
+            "LISTEN/NOTIFY is only available for PostgreSQL"
+        ))
+    }
 }
 
 #[derive(Clone)]
 pub struct DatabaseManager {
     client: Arc<dyn DatabaseClient>,
+    row_limit: usize,
+    read_only: bool,
 }
 
 impl DatabaseManager {
     pub async fn new(config: &Config) -> Result<Self> {
         let client = Self::create_database_client(config).await?;
-        Ok(Self { client })
+        Ok(Self::from_client(client, config))
+    }
+
+    /// A manager over an already connected `client`, with the row limit and read-only
+    /// setting taken from `config`.
+    pub fn from_client(client: Arc<dyn DatabaseClient>, config: &Config) -> Self {
+        Self {
+            client,
+            row_limit: config.database.get_row_limit(),
+            read_only: config.database.read_only,
+        }
+    }
+
+    pub fn row_limit(&self) -> usize {
+        self.row_limit
     }
 
     pub async fn execute_query(&self, query: &str) -> Result<QueryResult> {
-        self.client.execute_query(query).await
+        self.execute_query_with_limit(query, &[], self.row_limit)
+            .await
+    }
+
+    pub async fn execute_query_with_params(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+    ) -> Result<QueryResult> {
+        self.execute_query_with_limit(query, params, self.row_limit)
+            .await
+    }
+
+    pub async fn explain(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        mode: ExplainMode,
+    ) -> Result<serde_json::Value> {
+        if mode == ExplainMode::Analyze {
+            self.check_read_only(query)?;
+        }
+        self.client.explain(query, params, mode).await
+    }
+
+    pub async fn listen(
+        &self,
+        channels: &[String],
+    ) -> Result<BoxStream<'static, Result<ChannelNotification>>> {
+        self.client.listen(channels).await
+    }
+
+    pub fn cancel_query(&self) -> BoxFuture<'static, Result<()>> {
+        self.client.cancel_query()
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.client.in_transaction()
+    }
+
+    pub async fn execute_query_with_limit(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        row_limit: usize,
+    ) -> Result<QueryResult> {
+        self.check_read_only(query)?;
+        self.client.execute_query(query, params, row_limit).await
+    }
+
+    pub async fn fetch_more(&self) -> Result<QueryResult> {
+        self.client.fetch_more(self.row_limit).await
+    }
+
+    /// Refuses writes on a read-only connection before they reach the server.
+    fn check_read_only(&self, query: &str) -> Result<()> {
+        if !self.read_only {
+            return Ok(());
+        }
+        match SafetyChecker::new().read_only_violation(query) {
+            Some(reason) => Err(anyhow::anyhow!(
+                "Blocked on a read-only connection: {}",
+                reason
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn lookup_foreign_key(
+        &self,
+        fk_info: &ForeignKeyInfo,
+        values: &[Value],
+    ) -> Result<QueryResult> {
+        if values.len() != fk_info.referenced_columns.len() {
+            return Err(anyhow::anyhow!(
+                "Foreign key {} needs {} values, got {}",
+                fk_info.constraint_name,
+                fk_info.referenced_columns.len(),
+                values.len()
+            ));
+        }
+        self.client
+            .fetch_rows_by_key(
+                &fk_info.referenced_schema,
+                &fk_info.referenced_table,
+                &fk_info.referenced_columns,
+                values,
+                10,
+            )
+            .await
+    }
+
+    /// Rows of the referencing table that point at the key `values`.
+    pub async fn lookup_referencing_rows(
+        &self,
+        fk_info: &ForeignKeyInfo,
+        values: &[Value],
+    ) -> Result<QueryResult> {
+        self.client
+            .fetch_rows_by_key(
+                &fk_info.schema,
+                &fk_info.table_name,
+                &fk_info.columns,
+                values,
+                self.row_limit,
+            )
+            .await
+    }
+
+    pub async fn count_referencing_rows(
+        &self,
+        fk_info: &ForeignKeyInfo,
+        values: &[Value],
+    ) -> Result<i64> {
+        self.client
+            .count_rows_by_key(
+                &fk_info.schema,
+                &fk_info.table_name,
+                &fk_info.columns,
+                values,
+            )
+            .await
+    }
+
+    pub fn get_referencing_foreign_keys(
+        &self,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Vec<&ForeignKeyInfo> {
+        self.client.get_referencing_foreign_keys(schema, table)
+    }
+
+    pub fn get_foreign_key_info(&self, column: &ColumnRef) -> Option<&ForeignKeyInfo> {
+        self.client.get_foreign_key_info(column)
+    }
+
+    pub async fn introspect_schema(&self) -> Result<Vec<SchemaInfo>> {
+        self.client.introspect_schema().await
     }
 
-    pub async fn lookup_foreign_key(&self, column_name: &str, value: &str) -> Result<QueryResult> {
-        self.client.lookup_foreign_key(column_name, value).await
+    pub fn preview_query(&self, schema: &str, table: &str, limit: usize) -> String {
+        self.client.preview_query(schema, table, limit)
     }
 
-    pub fn get_foreign_key_info(&self, column_name: &str) -> Result<&ForeignKeyInfo> {
-        self.client.get_foreign_key_info(column_name)
+    pub fn foreign_key_warnings(&self) -> &[String] {
+        self.client.foreign_key_warnings()
     }
 
     async fn create_database_client(config: &Config) -> Result<Arc<dyn DatabaseClient>> {
-        let postgres_client = crate::database::postgres::PostgresClient::new(config).await?;
-        Ok(Arc::new(postgres_client))
+        match config.detect_database_type() {
+            Some(DatabaseType::SQLite) => {
+                let sqlite_client = crate::database::sqlite::SqliteClient::new(config).await?;
+                Ok(Arc::new(sqlite_client))
+            }
+            Some(DatabaseType::MySQL) => {
+                let mysql_client = crate::database::mysql::MySqlClient::new(config).await?;
+                Ok(Arc::new(mysql_client))
+            }
+            Some(DatabaseType::PostgreSQL) | None => {
+                let postgres_client =
+                    crate::database::postgres::PostgresClient::new(config).await?;
+                Ok(Arc::new(postgres_client))
+            }
+        }
     }
 }
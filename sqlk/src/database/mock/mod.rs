@@ -0,0 +1,218 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::database::{
+    ColumnRef, DatabaseClient, ExecutionOutcome, ForeignKeyInfo, QueryParam, QueryResult,
+    SchemaCache, SchemaInfo, Value,
+};
+use crate::query_parser::QueryAnalyzer;
+use crate::query_parser::analyzer::TransactionControl;
+
+/// A `DatabaseClient` that answers from responses scripted up front, so a `DatabaseManager`
+/// and the app around it can be tested without a server. Queries are matched on their text,
+/// ignoring surrounding whitespace and a trailing `;`.
+#[derive(Default)]
+pub struct MockDatabaseClient {
+    responses: HashMap<String, Response>,
+    fallback: Option<Response>,
+    /// Rows returned by key lookups, by `schema.table`.
+    key_lookups: HashMap<String, QueryResult>,
+    schemas: Vec<SchemaInfo>,
+    schema_cache: SchemaCache,
+    warnings: Vec<String>,
+    executed: Mutex<Vec<ExecutedQuery>>,
+    in_transaction: AtomicBool,
+}
+
+#[derive(Clone)]
+enum Response {
+    Result(QueryResult),
+    Error(String),
+}
+
+/// A call to `execute_query`, as the mock received it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutedQuery {
+    pub query: String,
+    pub params: Vec<QueryParam>,
+}
+
+impl MockDatabaseClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_result(mut self, query: &str, result: QueryResult) -> Self {
+        self.responses
+            .insert(normalize(query), Response::Result(result));
+        self
+    }
+
+    /// Fails `query` with `message`, as if the server had rejected it.
+    pub fn with_error(mut self, query: &str, message: &str) -> Self {
+        self.responses
+            .insert(normalize(query), Response::Error(message.to_string()));
+        self
+    }
+
+    /// Answers queries without a scripted response, which otherwise fail.
+    pub fn with_default_result(mut self, result: QueryResult) -> Self {
+        self.fallback = Some(Response::Result(result));
+        self
+    }
+
+    /// Rows of `schema.table` returned by foreign key lookups and counted by row counts.
+    pub fn with_rows_by_key(mut self, schema: &str, table: &str, result: QueryResult) -> Self {
+        self.key_lookups
+            .insert(format!("{}.{}", schema, table), result);
+        self
+    }
+
+    pub fn with_foreign_key(mut self, fk_info: ForeignKeyInfo) -> Self {
+        self.schema_cache.insert(fk_info);
+        self
+    }
+
+    pub fn with_foreign_key_warning(mut self, warning: &str) -> Self {
+        self.warnings.push(warning.to_string());
+        self
+    }
+
+    pub fn with_schemas(mut self, schemas: Vec<SchemaInfo>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
+    /// Every query sent to the mock so far, oldest first.
+    pub fn executed_queries(&self) -> Vec<ExecutedQuery> {
+        self.executed.lock().unwrap().clone()
+    }
+
+    fn key_lookup(&self, schema: &str, table: &str) -> Result<&QueryResult> {
+        self.key_lookups
+            .get(&format!("{}.{}", schema, table))
+            .ok_or_else(|| anyhow!("MockDatabaseClient has no rows for {}.{}", schema, table))
+    }
+}
+
+#[async_trait]
+impl DatabaseClient for MockDatabaseClient {
+    async fn execute_query(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        row_limit: usize,
+    ) -> Result<QueryResult> {
+        self.executed.lock().unwrap().push(ExecutedQuery {
+            query: query.to_string(),
+            params: params.to_vec(),
+        });
+
+        let response = self
+            .responses
+            .get(&normalize(query))
+            .or(self.fallback.as_ref())
+            .ok_or_else(|| anyhow!("MockDatabaseClient has no response for: {}", query))?;
+        let mut result = match response {
+            Response::Result(result) => result.clone(),
+            Response::Error(message) => return Err(anyhow!("{}", message)),
+        };
+
+        match QueryAnalyzer::new().transaction_control(query) {
+            Some(TransactionControl::Begin) => self.in_transaction.store(true, Ordering::SeqCst),
+            Some(TransactionControl::End) => self.in_transaction.store(false, Ordering::SeqCst),
+            None => {}
+        }
+        if result.rows.len() > row_limit {
+            result.rows.truncate(row_limit);
+            result.row_count = row_limit;
+            result.truncated = true;
+        }
+        Ok(result)
+    }
+
+    async fn fetch_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        _columns: &[String],
+        _values: &[Value],
+        limit: usize,
+    ) -> Result<QueryResult> {
+        let mut result = self.key_lookup(schema, table)?.clone();
+        result.rows.truncate(limit);
+        result.row_count = result.rows.len();
+        Ok(result)
+    }
+
+    async fn count_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        _columns: &[String],
+        _values: &[Value],
+    ) -> Result<i64> {
+        Ok(self.key_lookup(schema, table)?.rows.len() as i64)
+    }
+
+    fn get_foreign_key_info(&self, column: &ColumnRef) -> Option<&ForeignKeyInfo> {
+        self.schema_cache.get(column)
+    }
+
+    fn get_referencing_foreign_keys(
+        &self,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Vec<&ForeignKeyInfo> {
+        self.schema_cache.referencing(schema, table)
+    }
+
+    fn foreign_key_warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    async fn introspect_schema(&self) -> Result<Vec<SchemaInfo>> {
+        Ok(self.schemas.clone())
+    }
+
+    fn preview_query(&self, schema: &str, table: &str, limit: usize) -> String {
+        format!("SELECT * FROM \"{}\".\"{}\" LIMIT {}", schema, table, limit)
+    }
+
+    fn in_transaction(&self) -> bool {
+        self.in_transaction.load(Ordering::SeqCst)
+    }
+}
+
+/// A result set with `headers` and `rows`, every column typed as `TEXT`.
+pub fn rows_result(headers: &[&str], rows: Vec<Vec<Value>>) -> QueryResult {
+    QueryResult {
+        headers: headers.iter().map(|header| header.to_string()).collect(),
+        row_count: rows.len(),
+        rows,
+        execution_time: None,
+        column_types: vec!["TEXT".to_string(); headers.len()],
+        truncated: false,
+        column_sources: vec![None; headers.len()],
+        outcome: None,
+        notices: Vec::new(),
+    }
+}
+
+/// The result of a statement that returns no rows, e.g. `outcome_result("UPDATE", 3)`.
+pub fn outcome_result(command_tag: &str, rows_affected: u64) -> QueryResult {
+    QueryResult {
+        outcome: Some(ExecutionOutcome {
+            command_tag: command_tag.to_string(),
+            rows_affected,
+        }),
+        ..rows_result(&[], Vec::new())
+    }
+}
+
+fn normalize(query: &str) -> String {
+    query.trim().trim_end_matches(';').trim_end().to_string()
+}
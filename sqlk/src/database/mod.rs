@@ -1,26 +1,61 @@
+pub mod catalog;
 pub mod fk;
 pub mod manager;
+#[cfg(feature = "test-utils")]
+pub mod mock;
+pub mod mysql;
+pub mod notice;
+pub mod pager;
+pub mod param;
+pub mod pool;
 pub mod postgres;
 pub mod query_result;
+pub mod session;
+pub mod sqlite;
+pub mod timeout;
+pub mod tls;
+pub mod value;
 
+pub use catalog::{SchemaInfo, TableInfo, TableKind};
+pub use fk::{CatalogColumn, ColumnRef, SchemaCache};
 pub use manager::*;
-
-use std::collections::HashMap;
+pub use notice::{NoticeSeverity, ServerNotice};
+pub use param::QueryParam;
+pub use session::TransactionSession;
+pub use value::Value;
 
 #[derive(Debug, Clone)]
 pub struct QueryResult {
     pub headers: Vec<String>,
-    pub rows: Vec<Vec<String>>,
+    pub rows: Vec<Vec<Value>>,
     pub row_count: usize,
     pub execution_time: Option<std::time::Duration>,
     pub column_types: Vec<String>,
+    /// Set when the row limit was reached before the result set was exhausted.
+    pub truncated: bool,
+    /// The table column each result column was read from, where known.
+    pub column_sources: Vec<Option<ColumnRef>>,
+    /// Set for statements other than queries, such as DML and DDL.
+    pub outcome: Option<ExecutionOutcome>,
+    /// Notices and warnings the server sent while the statement ran.
+    pub notices: Vec<ServerNotice>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutionOutcome {
+    pub command_tag: String,
+    pub rows_affected: u64,
 }
 
 #[derive(Debug, Clone)]
 pub struct ForeignKeyInfo {
-    pub column_name: String,
+    pub constraint_name: String,
+    pub schema: String,
+    pub table_name: String,
+    /// Referencing columns in constraint order; more than one for composite keys.
+    pub columns: Vec<String>,
+    pub referenced_schema: String,
     pub referenced_table: String,
-    pub referenced_column: String,
+    /// Referenced columns, paired positionally with `columns`.
+    pub referenced_columns: Vec<String>,
 }
-
-pub type SchemaCache = HashMap<String, Vec<ForeignKeyInfo>>;
@@ -0,0 +1,438 @@
+use super::formatting::MySqlFormatter;
+use super::introspection;
+use crate::config::Config;
+use crate::database::fk::infer_column_sources;
+use crate::database::pager::{OpenResult, ResultSlot, read_page};
+use crate::database::param::params_for;
+use crate::database::{
+    CatalogColumn, ColumnRef, DatabaseClient, ExecutionOutcome, ForeignKeyInfo, QueryParam,
+    QueryResult, SchemaCache, SchemaInfo, TransactionSession, Value,
+};
+use crate::database::{pool, tls};
+use crate::query_parser::QueryAnalyzer;
+use crate::query_parser::placeholder::{find_placeholders, replace_placeholders};
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use sqlx::Row;
+use sqlx::mysql::{MySqlArguments, MySqlConnectOptions, MySqlConnection, MySqlRow};
+use sqlx::query::Query;
+use sqlx::{Either, Executor, MySql, MySqlPool};
+use std::str::FromStr;
+use std::time::Instant;
+
+#[derive(Clone)]
+pub struct MySqlClient {
+    pool: MySqlPool,
+    schema_cache: SchemaCache,
+    formatter: MySqlFormatter,
+    session: TransactionSession<MySql>,
+    open_result: ResultSlot<MySqlRow>,
+}
+
+impl MySqlClient {
+    pub async fn new(config: &Config) -> Result<Self> {
+        let database_url = config
+            .get_database_url()
+            .ok_or_else(|| anyhow::anyhow!("No DATABASE_URL found"))?;
+
+        let mut options = MySqlConnectOptions::from_str(database_url)?;
+        if let Some(schema) = &config.database.default_schema {
+            options = options.database(schema);
+        }
+        let tls = &config.database.tls;
+        tls.validate()?;
+        if let Some(mode) = tls.sslmode {
+            options = options.ssl_mode(tls::mysql_ssl_mode(mode));
+        }
+        if let Some(path) = &tls.sslrootcert {
+            options = options.ssl_ca(path);
+        }
+        if let Some(path) = &tls.sslcert {
+            options = options.ssl_client_cert(path);
+        }
+        if let Some(path) = &tls.sslkey {
+            options = options.ssl_client_key(path);
+        }
+        let setup = pool::SessionSetup::new(&config.database, "SET SESSION TRANSACTION READ ONLY");
+        let pool_options = pool::pool_options::<MySql>(&config.database.pool).after_connect({
+            let setup = setup.clone();
+            move |connection, _| {
+                let setup = setup.clone();
+                Box::pin(async move { setup.run(connection).await })
+            }
+        });
+        let pool = setup.connect(pool_options, options).await?;
+        let formatter = MySqlFormatter::new();
+
+        let mut client = Self {
+            pool,
+            schema_cache: SchemaCache::new(),
+            formatter,
+            session: TransactionSession::new(),
+            open_result: ResultSlot::new(),
+        };
+
+        if config.foreign_keys.enabled {
+            client.schema_cache = client.analyze_schema(&config.foreign_keys.schemas).await?;
+            if !config.foreign_keys.manual_mapping.is_empty() {
+                let columns = client.catalog_columns(&config.foreign_keys.schemas).await?;
+                client
+                    .schema_cache
+                    .apply_manual_mapping(&config.foreign_keys.manual_mapping, &columns);
+            }
+        }
+
+        Ok(client)
+    }
+
+    async fn analyze_schema(&self, schemas: &[String]) -> Result<SchemaCache> {
+        let mut schema_cache = SchemaCache::new();
+        let query = format!(
+            r#"
+            SELECT
+                kcu.CONSTRAINT_NAME AS constraint_name,
+                kcu.TABLE_SCHEMA AS table_schema,
+                kcu.TABLE_NAME AS table_name,
+                kcu.COLUMN_NAME AS column_name,
+                kcu.REFERENCED_TABLE_SCHEMA AS foreign_table_schema,
+                kcu.REFERENCED_TABLE_NAME AS foreign_table_name,
+                kcu.REFERENCED_COLUMN_NAME AS foreign_column_name
+            FROM
+                information_schema.KEY_COLUMN_USAGE AS kcu
+            WHERE kcu.TABLE_SCHEMA {} AND kcu.REFERENCED_TABLE_NAME IS NOT NULL
+            ORDER BY kcu.TABLE_SCHEMA, kcu.TABLE_NAME, kcu.CONSTRAINT_NAME, kcu.ORDINAL_POSITION;
+        "#,
+            schema_filter(schemas)
+        );
+
+        let rows = schemas
+            .iter()
+            .fold(sqlx::query(&query), |query, schema| query.bind(schema))
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut foreign_keys: Vec<ForeignKeyInfo> = Vec::new();
+        for row in rows {
+            let constraint_name: String = row.try_get_unchecked("constraint_name")?;
+            let schema: String = row.try_get_unchecked("table_schema")?;
+            let table_name: String = row.try_get_unchecked("table_name")?;
+            let column_name: String = row.try_get_unchecked("column_name")?;
+            let referenced_column: String = row.try_get_unchecked("foreign_column_name")?;
+
+            // Rows are ordered so the components of a composite key arrive together.
+            match foreign_keys.last_mut() {
+                Some(fk_info)
+                    if fk_info.constraint_name == constraint_name
+                        && fk_info.schema == schema
+                        && fk_info.table_name == table_name =>
+                {
+                    fk_info.columns.push(column_name);
+                    fk_info.referenced_columns.push(referenced_column);
+                }
+                _ => foreign_keys.push(ForeignKeyInfo {
+                    constraint_name,
+                    schema,
+                    table_name,
+                    columns: vec![column_name],
+                    referenced_schema: row.try_get_unchecked("foreign_table_schema")?,
+                    referenced_table: row.try_get_unchecked("foreign_table_name")?,
+                    referenced_columns: vec![referenced_column],
+                }),
+            }
+        }
+
+        for fk_info in foreign_keys {
+            schema_cache.insert(fk_info);
+        }
+
+        Ok(schema_cache)
+    }
+
+    /// Every column in the scanned schemas, for resolving manual foreign key mappings.
+    async fn catalog_columns(&self, schemas: &[String]) -> Result<Vec<CatalogColumn>> {
+        let query = format!(
+            r#"
+            SELECT TABLE_SCHEMA AS table_schema, TABLE_NAME AS table_name, COLUMN_NAME AS column_name
+            FROM information_schema.COLUMNS
+            WHERE TABLE_SCHEMA {}
+            ORDER BY TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION;
+        "#,
+            schema_filter(schemas)
+        );
+
+        let rows = schemas
+            .iter()
+            .fold(sqlx::query(&query), |query, schema| query.bind(schema))
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok((
+                    row.try_get_unchecked("table_schema")?,
+                    row.try_get_unchecked("table_name")?,
+                    row.try_get_unchecked("column_name")?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Restricts `TABLE_SCHEMA` to `schemas`, one placeholder each, or to the connected database.
+fn schema_filter(schemas: &[String]) -> String {
+    if schemas.is_empty() {
+        "= DATABASE()".to_string()
+    } else {
+        format!("IN ({})", vec!["?"; schemas.len()].join(", "))
+    }
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('`', "``"))
+}
+
+/// `schema.table WHERE c1 = .. AND c2 = ..`, with one placeholder per key column.
+fn key_filter(schema: &str, table: &str, columns: &[String]) -> String {
+    let conditions = columns
+        .iter()
+        .map(|column| format!("{} = ?", quote_identifier(column)))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    format!(
+        "{}.{} WHERE {}",
+        quote_identifier(schema),
+        quote_identifier(table),
+        conditions
+    )
+}
+
+fn bind_value<'q>(
+    query: Query<'q, MySql, MySqlArguments>,
+    value: &Value,
+) -> Query<'q, MySql, MySqlArguments> {
+    match value {
+        Value::Null => query.bind(None::<String>),
+        Value::Bool(val) => query.bind(*val),
+        Value::Int(val) => query.bind(*val),
+        Value::Float(val) => query.bind(*val),
+        Value::Decimal(val) | Value::Text(val) | Value::Other(val) => query.bind(val.clone()),
+        Value::Bytes(val) => query.bind(val.clone()),
+        Value::Json(val) => query.bind(sqlx::types::Json(val.clone())),
+        Value::Date(val) => query.bind(*val),
+        Value::Time(val) => query.bind(*val),
+        Value::Timestamp(val) => query.bind(*val),
+        Value::TimestampTz(val) => query.bind(*val),
+        Value::Uuid(val) => query.bind(val.to_string()),
+    }
+}
+
+fn fetch_rows<'c>(
+    conn: &'c mut MySqlConnection,
+    statement: &'c str,
+    values: Vec<Value>,
+    simple: bool,
+) -> BoxStream<'c, sqlx::Result<Either<u64, MySqlRow>>> {
+    let stream = if simple {
+        sqlx::raw_sql(statement).fetch_many(conn)
+    } else {
+        let mut bound = sqlx::query(statement);
+        for value in &values {
+            bound = bind_value(bound, value);
+        }
+        conn.fetch_many(bound)
+    };
+    stream
+        .map_ok(|item| item.map_left(|done| done.rows_affected()))
+        .boxed()
+}
+
+/// Rewrites placeholders as `?` and returns one value per occurrence, since MySQL
+/// parameters are positional only.
+fn positional_placeholders(query: &str, params: &[QueryParam]) -> Result<(String, Vec<Value>)> {
+    if params.is_empty() {
+        return Ok((query.to_string(), Vec::new()));
+    }
+    let placeholders = find_placeholders(query);
+    let names: Vec<String> = placeholders
+        .iter()
+        .map(|placeholder| placeholder.name.clone())
+        .collect();
+    let values = params_for(&names, params)?
+        .into_iter()
+        .map(QueryParam::value)
+        .collect();
+    let statement = replace_placeholders(query, &placeholders, |_, _| "?".to_string());
+    Ok((statement, values))
+}
+
+#[async_trait]
+impl DatabaseClient for MySqlClient {
+    async fn execute_query(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        row_limit: usize,
+    ) -> Result<QueryResult> {
+        let start_time = Instant::now();
+        // The viewer is about to show a new result, so the last one's rows are not needed.
+        self.open_result.close();
+        let analyzer = QueryAnalyzer::new();
+        let command_tag = analyzer.command_tag(query);
+        let control = analyzer.transaction_control(query);
+        // Blocks such as `BEGIN; ...; COMMIT` cannot be prepared, so they go over the text
+        // protocol.
+        let simple = params.is_empty() && analyzer.is_multi_statement(query, true);
+        let (statement, values) = if simple {
+            (query.to_string(), Vec::new())
+        } else {
+            positional_placeholders(query, params)?
+        };
+        let mut session = self.session.checkout(&self.pool, control).await?;
+        // Statements with side effects run to completion so the count is right, and a pinned
+        // connection has to stay usable for the rest of the transaction.
+        let drain = command_tag.is_some() || session.is_pinned();
+
+        let fetched = if drain || control.is_some() {
+            let conn = session.connection();
+            let fetched = async {
+                let mut stream = fetch_rows(conn, &statement, values, simple);
+                let page = read_page(&mut stream, &mut None, row_limit, drain).await?;
+                drop(stream);
+                if page.more && !drain {
+                    conn.close_on_drop();
+                }
+                anyhow::Ok(page)
+            }
+            .await;
+            session.finish(fetched.is_ok());
+            fetched?
+        } else {
+            let mut conn = session
+                .take_pooled()
+                .expect("an unpinned checkout holds a pooled connection");
+            // The rest of the result stays open on a task of its own, so more rows can be
+            // read later without running the query again.
+            let (open, requests) = OpenResult::new();
+            tokio::spawn(async move {
+                let mut stream = fetch_rows(&mut conn, &statement, values, simple);
+                let unread = requests.serve(&mut stream).await;
+                drop(stream);
+                if unread {
+                    // The server keeps sending the remaining rows, so closing the connection
+                    // is cheaper than draining them before it can be reused.
+                    conn.close_on_drop();
+                }
+            });
+            let page = open.next_page(row_limit).await?;
+            if page.more {
+                self.open_result.keep(open);
+            }
+            page
+        };
+
+        let mut result = self
+            .formatter
+            .rows_to_query_result(fetched.rows, start_time)?;
+        result.truncated = fetched.more;
+        result.outcome = command_tag.map(|command_tag| ExecutionOutcome {
+            command_tag,
+            rows_affected: fetched.rows_affected,
+        });
+        result.column_sources = infer_column_sources(&self.schema_cache, query, &result.headers);
+        Ok(result)
+    }
+
+    async fn fetch_more(&self, row_limit: usize) -> Result<QueryResult> {
+        let start_time = Instant::now();
+        let page = self.open_result.next_page(row_limit).await?;
+        let mut result = self.formatter.rows_to_query_result(page.rows, start_time)?;
+        result.truncated = page.more;
+        Ok(result)
+    }
+
+    async fn fetch_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+        limit: usize,
+    ) -> Result<QueryResult> {
+        let query_string = format!(
+            "SELECT * FROM {} LIMIT {}",
+            key_filter(schema, table, columns),
+            limit + 1
+        );
+
+        let mut session = self.session.checkout(&self.pool, None).await?;
+        let start_time = Instant::now();
+        let mut rows = values
+            .iter()
+            .fold(sqlx::query(&query_string), bind_value)
+            .fetch_all(&mut **session.connection())
+            .await?;
+        let truncated = rows.len() > limit;
+        rows.truncate(limit);
+
+        let mut result = self.formatter.rows_to_query_result(rows, start_time)?;
+        result.truncated = truncated;
+        result.column_sources = ColumnRef::for_table(schema, table, &result.headers);
+        Ok(result)
+    }
+
+    async fn count_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+    ) -> Result<i64> {
+        let query_string = format!(
+            "SELECT COUNT(*) FROM {}",
+            key_filter(schema, table, columns)
+        );
+        let mut session = self.session.checkout(&self.pool, None).await?;
+        let count = values
+            .iter()
+            .fold(sqlx::query(&query_string), bind_value)
+            .fetch_one(&mut **session.connection())
+            .await?
+            .try_get(0)?;
+        Ok(count)
+    }
+
+    fn get_foreign_key_info(&self, column: &ColumnRef) -> Option<&ForeignKeyInfo> {
+        self.schema_cache.get(column)
+    }
+
+    fn get_referencing_foreign_keys(
+        &self,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Vec<&ForeignKeyInfo> {
+        self.schema_cache.referencing(schema, table)
+    }
+
+    fn foreign_key_warnings(&self) -> &[String] {
+        self.schema_cache.warnings()
+    }
+
+    async fn introspect_schema(&self) -> Result<Vec<SchemaInfo>> {
+        introspection::introspect_schema(&self.pool).await
+    }
+
+    fn preview_query(&self, schema: &str, table: &str, limit: usize) -> String {
+        format!(
+            "SELECT * FROM {}.{} LIMIT {}",
+            quote_identifier(schema),
+            quote_identifier(table),
+            limit
+        )
+    }
+
+    fn in_transaction(&self) -> bool {
+        self.session.is_active()
+    }
+}
@@ -0,0 +1,157 @@
+use crate::database::{QueryResult, Value};
+use anyhow::Result;
+use sqlx::{Column, Row, TypeInfo, ValueRef, mysql::MySqlRow};
+use std::time::Instant;
+
+#[derive(Clone)]
+pub struct MySqlFormatter;
+
+impl Default for MySqlFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MySqlFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn rows_to_query_result(
+        &self,
+        rows: Vec<MySqlRow>,
+        start_time: Instant,
+    ) -> Result<QueryResult> {
+        let execution_time = start_time.elapsed();
+
+        if rows.is_empty() {
+            return Ok(QueryResult {
+                headers: vec![],
+                rows: vec![],
+                row_count: 0,
+                execution_time: Some(execution_time),
+                column_types: vec![],
+                truncated: false,
+                column_sources: vec![],
+                outcome: None,
+                notices: Vec::new(),
+            });
+        }
+
+        let headers: Vec<String> = rows[0]
+            .columns()
+            .iter()
+            .map(|col| col.name().to_string())
+            .collect();
+
+        let column_types: Vec<String> = rows[0]
+            .columns()
+            .iter()
+            .map(|col| self.normalize_type_name(col.type_info().name()))
+            .collect();
+
+        let mut result_rows = Vec::new();
+        for row in &rows {
+            let mut value_row = Vec::new();
+            for col_idx in 0..row.columns().len() {
+                let value = self.decode_value(row, col_idx)?;
+                value_row.push(value);
+            }
+            result_rows.push(value_row);
+        }
+
+        Ok(QueryResult {
+            headers,
+            rows: result_rows,
+            row_count: rows.len(),
+            execution_time: Some(execution_time),
+            column_types,
+            truncated: false,
+            column_sources: vec![],
+            outcome: None,
+            notices: Vec::new(),
+        })
+    }
+
+    fn normalize_type_name(&self, type_name: &str) -> String {
+        match type_name {
+            "VARCHAR" | "CHAR" | "TEXT" | "TINYTEXT" | "MEDIUMTEXT" | "LONGTEXT" => {
+                "TEXT".to_string()
+            }
+            "TINYINT" | "TINYINT UNSIGNED" => "TINYINT".to_string(),
+            "SMALLINT" | "SMALLINT UNSIGNED" => "SMALLINT".to_string(),
+            "INT" | "INT UNSIGNED" | "MEDIUMINT" | "MEDIUMINT UNSIGNED" => "INTEGER".to_string(),
+            "BIGINT" | "BIGINT UNSIGNED" => "BIGINT".to_string(),
+            "FLOAT" => "REAL".to_string(),
+            "DOUBLE" => "DOUBLE PRECISION".to_string(),
+            "DECIMAL" => "NUMERIC".to_string(),
+            "DATETIME" => "TIMESTAMP".to_string(),
+            "BINARY" | "VARBINARY" | "BLOB" | "TINYBLOB" | "MEDIUMBLOB" | "LONGBLOB" => {
+                "BLOB".to_string()
+            }
+            other => other.to_string(),
+        }
+    }
+
+    fn decode_value(&self, row: &MySqlRow, col_idx: usize) -> Result<Value> {
+        let value_ref = row.try_get_raw(col_idx)?;
+        if value_ref.is_null() {
+            return Ok(Value::Null);
+        }
+
+        let type_info = value_ref.type_info();
+        let type_name = type_info.name();
+
+        match type_name {
+            "TINYINT" | "SMALLINT" | "INT" | "MEDIUMINT" | "BIGINT" | "YEAR" => Ok(row
+                .try_get_unchecked::<i64, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Int)),
+            "TINYINT UNSIGNED" | "SMALLINT UNSIGNED" | "INT UNSIGNED" | "MEDIUMINT UNSIGNED"
+            | "BIGINT UNSIGNED" => Ok(row.try_get_unchecked::<u64, _>(col_idx).map_or_else(
+                |_| Value::unsupported(type_name),
+                |val| match i64::try_from(val) {
+                    Ok(val) => Value::Int(val),
+                    Err(_) => Value::Decimal(val.to_string()),
+                },
+            )),
+            "FLOAT" => Ok(row
+                .try_get::<f32, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::from)),
+            "DOUBLE" => Ok(row
+                .try_get::<f64, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Float)),
+            // DECIMAL travels as its exact textual representation, so no precision is lost.
+            "DECIMAL" => Ok(row
+                .try_get_unchecked::<String, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Decimal)),
+            "BOOLEAN" => Ok(row
+                .try_get::<bool, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Bool)),
+            "DATETIME" => Ok(row
+                .try_get::<chrono::NaiveDateTime, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Timestamp)),
+            "TIMESTAMP" => Ok(row
+                .try_get::<chrono::DateTime<chrono::Utc>, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::TimestampTz)),
+            "DATE" => Ok(row
+                .try_get::<chrono::NaiveDate, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Date)),
+            "TIME" => Ok(row
+                .try_get::<chrono::NaiveTime, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Time)),
+            "JSON" => Ok(row
+                .try_get::<serde_json::Value, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Json)),
+            "BINARY" | "VARBINARY" | "BLOB" | "TINYBLOB" | "MEDIUMBLOB" | "LONGBLOB" | "BIT"
+            | "GEOMETRY" => Ok(row
+                .try_get_unchecked::<Vec<u8>, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Bytes)),
+            "ENUM" | "SET" => Ok(row
+                .try_get_unchecked::<String, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Other)),
+            _ => Ok(row
+                .try_get_unchecked::<String, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Text)),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use crate::database::catalog::{
+    CatalogBuilder, ColumnInfo, ConstraintInfo, IndexInfo, SchemaInfo, TableKind,
+};
+use anyhow::Result;
+use sqlx::{MySqlPool, Row};
+
+const USER_SCHEMAS: &str = "NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')";
+
+/// Reads every non-system database from `information_schema`.
+pub async fn introspect_schema(pool: &MySqlPool) -> Result<Vec<SchemaInfo>> {
+    let mut catalog = CatalogBuilder::new();
+
+    let schemas = sqlx::query(&format!(
+        "SELECT SCHEMA_NAME AS schema_name FROM information_schema.SCHEMATA \
+         WHERE SCHEMA_NAME {} ORDER BY SCHEMA_NAME",
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in schemas {
+        let schema: String = row.try_get_unchecked("schema_name")?;
+        catalog.add_schema(&schema);
+    }
+
+    let tables = sqlx::query(&format!(
+        r#"
+        SELECT
+            TABLE_SCHEMA AS table_schema,
+            TABLE_NAME AS table_name,
+            TABLE_TYPE AS table_type,
+            TABLE_ROWS AS table_rows
+        FROM information_schema.TABLES
+        WHERE TABLE_SCHEMA {}
+        ORDER BY TABLE_SCHEMA, TABLE_NAME
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in tables {
+        let schema: String = row.try_get_unchecked("table_schema")?;
+        let name: String = row.try_get_unchecked("table_name")?;
+        let table_type: String = row.try_get_unchecked("table_type")?;
+        let kind = if table_type == "VIEW" {
+            TableKind::View
+        } else {
+            TableKind::Table
+        };
+        let estimated_rows: Option<u64> = row.try_get_unchecked("table_rows")?;
+        catalog.add_table(&schema, &name, kind, estimated_rows.map(|rows| rows as i64));
+    }
+
+    let columns = sqlx::query(&format!(
+        r#"
+        SELECT
+            TABLE_SCHEMA AS table_schema,
+            TABLE_NAME AS table_name,
+            COLUMN_NAME AS column_name,
+            COLUMN_TYPE AS column_type,
+            IS_NULLABLE AS is_nullable,
+            COLUMN_DEFAULT AS column_default
+        FROM information_schema.COLUMNS
+        WHERE TABLE_SCHEMA {}
+        ORDER BY TABLE_SCHEMA, TABLE_NAME, ORDINAL_POSITION
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in columns {
+        let schema: String = row.try_get_unchecked("table_schema")?;
+        let name: String = row.try_get_unchecked("table_name")?;
+        if let Some(table) = catalog.table_mut(&schema, &name) {
+            let nullable: String = row.try_get_unchecked("is_nullable")?;
+            table.columns.push(ColumnInfo {
+                name: row.try_get_unchecked("column_name")?,
+                data_type: row.try_get_unchecked("column_type")?,
+                nullable: nullable == "YES",
+                default: row.try_get_unchecked("column_default")?,
+            });
+        }
+    }
+
+    // One row per index column, ordered so each index's columns arrive together.
+    let indexes = sqlx::query(&format!(
+        r#"
+        SELECT
+            TABLE_SCHEMA AS table_schema,
+            TABLE_NAME AS table_name,
+            INDEX_NAME AS index_name,
+            NON_UNIQUE AS non_unique,
+            COLUMN_NAME AS column_name
+        FROM information_schema.STATISTICS
+        WHERE TABLE_SCHEMA {}
+        ORDER BY TABLE_SCHEMA, TABLE_NAME, INDEX_NAME = 'PRIMARY' DESC, INDEX_NAME, SEQ_IN_INDEX
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in indexes {
+        let schema: String = row.try_get_unchecked("table_schema")?;
+        let name: String = row.try_get_unchecked("table_name")?;
+        let index_name: String = row.try_get_unchecked("index_name")?;
+        // Expression indexes have no column name.
+        let column: Option<String> = row.try_get_unchecked("column_name")?;
+        let column = column.unwrap_or_else(|| "(expression)".to_string());
+        let non_unique: i64 = row.try_get_unchecked("non_unique")?;
+        let Some(table) = catalog.table_mut(&schema, &name) else {
+            continue;
+        };
+        match table.indexes.last_mut() {
+            Some(index) if index.name == index_name => index.columns.push(column),
+            _ => table.indexes.push(IndexInfo {
+                primary: index_name == "PRIMARY",
+                name: index_name,
+                columns: vec![column],
+                unique: non_unique == 0,
+            }),
+        }
+    }
+
+    let constraints = sqlx::query(&format!(
+        r#"
+        SELECT
+            tc.TABLE_SCHEMA AS table_schema,
+            tc.TABLE_NAME AS table_name,
+            tc.CONSTRAINT_NAME AS constraint_name,
+            tc.CONSTRAINT_TYPE AS constraint_type,
+            kcu.COLUMN_NAME AS column_name,
+            kcu.REFERENCED_TABLE_NAME AS referenced_table,
+            kcu.REFERENCED_COLUMN_NAME AS referenced_column
+        FROM information_schema.TABLE_CONSTRAINTS AS tc
+            LEFT JOIN information_schema.KEY_COLUMN_USAGE AS kcu
+                ON kcu.CONSTRAINT_SCHEMA = tc.CONSTRAINT_SCHEMA
+                AND kcu.TABLE_NAME = tc.TABLE_NAME
+                AND kcu.CONSTRAINT_NAME = tc.CONSTRAINT_NAME
+        WHERE tc.TABLE_SCHEMA {}
+        ORDER BY tc.TABLE_SCHEMA, tc.TABLE_NAME, tc.CONSTRAINT_TYPE, tc.CONSTRAINT_NAME,
+            kcu.ORDINAL_POSITION
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+
+    // One row per key column; constraints without columns (CHECK) come through once.
+    let mut gathered: Vec<KeyConstraint> = Vec::new();
+    for row in constraints {
+        let schema: String = row.try_get_unchecked("table_schema")?;
+        let table: String = row.try_get_unchecked("table_name")?;
+        let name: String = row.try_get_unchecked("constraint_name")?;
+        let column: Option<String> = row.try_get_unchecked("column_name")?;
+        let referenced_column: Option<String> = row.try_get_unchecked("referenced_column")?;
+        match gathered.last_mut() {
+            Some(entry) if entry.schema == schema && entry.table == table && entry.name == name => {
+                entry.columns.extend(column);
+                entry.referenced_columns.extend(referenced_column);
+            }
+            _ => gathered.push(KeyConstraint {
+                schema,
+                table,
+                name,
+                constraint_type: row.try_get_unchecked("constraint_type")?,
+                columns: column.into_iter().collect(),
+                referenced_table: row.try_get_unchecked("referenced_table")?,
+                referenced_columns: referenced_column.into_iter().collect(),
+            }),
+        }
+    }
+    for constraint in gathered {
+        if let Some(table) = catalog.table_mut(&constraint.schema, &constraint.table) {
+            table.constraints.push(ConstraintInfo {
+                definition: constraint.definition(),
+                name: constraint.name,
+            });
+        }
+    }
+
+    Ok(catalog.build())
+}
+
+struct KeyConstraint {
+    schema: String,
+    table: String,
+    name: String,
+    constraint_type: String,
+    columns: Vec<String>,
+    referenced_table: Option<String>,
+    referenced_columns: Vec<String>,
+}
+
+impl KeyConstraint {
+    fn definition(&self) -> String {
+        match &self.referenced_table {
+            Some(referenced_table) => format!(
+                "FOREIGN KEY ({}) REFERENCES {}({})",
+                self.columns.join(", "),
+                referenced_table,
+                self.referenced_columns.join(", ")
+            ),
+            None if self.columns.is_empty() => self.constraint_type.clone(),
+            None => format!("{} ({})", self.constraint_type, self.columns.join(", ")),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+
+/// Severity of a message the server sent alongside a statement's results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoticeSeverity {
+    Warning,
+    Notice,
+    /// `INFO` and `LOG`, which the driver does not tell apart.
+    Info,
+    Debug,
+}
+
+impl NoticeSeverity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NoticeSeverity::Warning => "WARNING",
+            NoticeSeverity::Notice => "NOTICE",
+            NoticeSeverity::Info => "INFO",
+            NoticeSeverity::Debug => "DEBUG",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerNotice {
+    pub severity: NoticeSeverity,
+    pub message: String,
+}
+
+impl fmt::Display for ServerNotice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity.label(), self.message)
+    }
+}
+
+/// Context on the error of a failed statement, keeping the notices the server sent before
+/// it failed. Displays as the original error so messages read the same either way.
+#[derive(Debug)]
+pub struct FailedWithNotices {
+    message: String,
+    pub notices: Vec<ServerNotice>,
+}
+
+impl fmt::Display for FailedWithNotices {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+pub fn attach_notices(error: anyhow::Error, notices: Vec<ServerNotice>) -> anyhow::Error {
+    if notices.is_empty() {
+        return error;
+    }
+    let message = error.to_string();
+    error.context(FailedWithNotices { message, notices })
+}
+
+/// Notices sent before the statement behind `error` failed.
+pub fn notices_of(error: &anyhow::Error) -> &[ServerNotice] {
+    error
+        .downcast_ref::<FailedWithNotices>()
+        .map(|failed| failed.notices.as_slice())
+        .unwrap_or_default()
+}
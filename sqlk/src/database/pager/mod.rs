@@ -0,0 +1,155 @@
+use anyhow::{Result, anyhow};
+use futures::{Stream, TryStreamExt};
+use sqlx::Either;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+
+const CLOSED: &str = "The rest of this result is no longer available";
+
+/// Rows read from a result, with the counts its statements reported meanwhile.
+pub struct Page<R> {
+    pub rows: Vec<R>,
+    pub rows_affected: u64,
+    /// Whether the result had rows left after this page.
+    pub more: bool,
+}
+
+/// Reads up to `limit` rows from `stream`, starting with the row the previous page held over
+/// in `pending`. Without `drain` the first row that does not fit is held over in turn and the
+/// rest are left unread; with it they are read and dropped, so the statements run to the end.
+pub async fn read_page<R, S>(
+    stream: &mut S,
+    pending: &mut Option<R>,
+    limit: usize,
+    drain: bool,
+) -> sqlx::Result<Page<R>>
+where
+    S: Stream<Item = sqlx::Result<Either<u64, R>>> + Unpin,
+{
+    let mut rows: Vec<R> = pending.take().into_iter().collect();
+    let mut rows_affected = 0;
+    let mut more = false;
+    while let Some(item) = stream.try_next().await? {
+        match item {
+            Either::Left(count) => rows_affected += count,
+            Either::Right(row) if rows.len() == limit => {
+                more = true;
+                if !drain {
+                    *pending = Some(row);
+                    break;
+                }
+            }
+            Either::Right(row) => rows.push(row),
+        }
+    }
+    Ok(Page {
+        rows,
+        rows_affected,
+        more,
+    })
+}
+
+type PageRequest<R> = (usize, oneshot::Sender<sqlx::Result<Page<R>>>);
+
+/// A result kept open between pages by a task that owns its connection, so more rows can be
+/// read without running the statement again. Dropping it ends the task.
+pub struct OpenResult<R> {
+    requests: mpsc::Sender<PageRequest<R>>,
+}
+
+/// The task's end of an [`OpenResult`].
+pub struct PageRequests<R> {
+    requests: mpsc::Receiver<PageRequest<R>>,
+}
+
+impl<R> OpenResult<R> {
+    pub fn new() -> (Self, PageRequests<R>) {
+        let (sender, receiver) = mpsc::channel(1);
+        (
+            Self { requests: sender },
+            PageRequests { requests: receiver },
+        )
+    }
+
+    /// The next `limit` rows, waiting for the task to read them.
+    pub async fn next_page(&self, limit: usize) -> Result<Page<R>> {
+        let (reply, page) = oneshot::channel();
+        self.requests
+            .send((limit, reply))
+            .await
+            .map_err(|_| anyhow!(CLOSED))?;
+        Ok(page.await.map_err(|_| anyhow!(CLOSED))??)
+    }
+}
+
+impl<R> PageRequests<R> {
+    /// Answers page requests from `stream` until the rows run out or the [`OpenResult`] is
+    /// dropped. Returns whether rows were left unread, in which case the connection is still
+    /// receiving them and is better closed than reused.
+    pub async fn serve<S>(mut self, stream: &mut S) -> bool
+    where
+        S: Stream<Item = sqlx::Result<Either<u64, R>>> + Unpin,
+    {
+        let mut pending = None;
+        while let Some((limit, mut reply)) = self.requests.recv().await {
+            let page = tokio::select! {
+                page = read_page(stream, &mut pending, limit, false) => page,
+                // Nobody waits for the page any more, e.g. because the query was cancelled.
+                _ = reply.closed() => return true,
+            };
+            let more = matches!(page, Ok(Page { more: true, .. }));
+            let _ = reply.send(page);
+            if !more {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Where a client keeps the result of its last query while it has rows left to page through.
+pub struct ResultSlot<R> {
+    open: Arc<Mutex<Option<OpenResult<R>>>>,
+}
+
+impl<R> ResultSlot<R> {
+    pub fn new() -> Self {
+        Self {
+            open: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn keep(&self, open: OpenResult<R>) {
+        *self.open.lock().unwrap() = Some(open);
+    }
+
+    /// Gives up the kept result, releasing its connection.
+    pub fn close(&self) {
+        self.open.lock().unwrap().take();
+    }
+
+    /// The next `limit` rows of the kept result, which stays kept while rows remain.
+    pub async fn next_page(&self, limit: usize) -> Result<Page<R>> {
+        let open = self.open.lock().unwrap().take();
+        let open = open.ok_or_else(|| anyhow!(CLOSED))?;
+        let page = open.next_page(limit).await?;
+        if page.more {
+            self.keep(open);
+        }
+        Ok(page)
+    }
+}
+
+impl<R> Default for ResultSlot<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> Clone for ResultSlot<R> {
+    fn clone(&self) -> Self {
+        Self {
+            open: Arc::clone(&self.open),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::Result;
+
+use crate::database::Value;
+
+/// A value entered for a query placeholder, bound as a parameter rather than spliced into
+/// the SQL. Typing `NULL` binds SQL `NULL`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryParam {
+    /// The placeholder as written in the query, e.g. `:user_id`.
+    pub name: String,
+    pub input: String,
+}
+
+impl QueryParam {
+    pub fn new(name: impl Into<String>, input: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            input: input.into(),
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.input.eq_ignore_ascii_case("null")
+    }
+
+    /// The input as text, or `None` for `NULL`.
+    pub fn text(&self) -> Option<&str> {
+        (!self.is_null()).then_some(self.input.as_str())
+    }
+
+    /// The input typed by its shape, for backends that bind loosely typed parameters, so
+    /// that `LIMIT :n` still receives an integer. Only canonical numbers are converted, so
+    /// text such as `007` keeps its leading zeros.
+    pub fn value(&self) -> Value {
+        if self.is_null() {
+            return Value::Null;
+        }
+        if let Ok(int) = self.input.parse::<i64>()
+            && int.to_string() == self.input
+        {
+            return Value::Int(int);
+        }
+        if let Ok(float) = self.input.parse::<f64>()
+            && float.is_finite()
+            && float.to_string() == self.input
+        {
+            return Value::Float(float);
+        }
+        Value::Text(self.input.clone())
+    }
+}
+
+/// The value for each of `names`, in the same order.
+pub fn params_for<'a>(names: &[String], params: &'a [QueryParam]) -> Result<Vec<&'a QueryParam>> {
+    names
+        .iter()
+        .map(|name| {
+            params
+                .iter()
+                .find(|param| param.name == *name)
+                .ok_or_else(|| anyhow::anyhow!("No value given for {}", name))
+        })
+        .collect()
+}
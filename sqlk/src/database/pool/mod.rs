@@ -0,0 +1,103 @@
+use anyhow::{Result, anyhow};
+use sqlx::pool::PoolOptions;
+use sqlx::{Connection, Database, Executor, Pool};
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+use crate::config::{DatabaseConfig, PoolConfig};
+use crate::database::tls;
+
+/// Pool options with the sizing and timeouts from `[database.pool]` applied.
+pub fn pool_options<DB: Database>(config: &PoolConfig) -> PoolOptions<DB> {
+    let mut options = PoolOptions::<DB>::new();
+    if let Some(max_connections) = config.max_connections {
+        options = options.max_connections(max_connections);
+    }
+    if let Some(min_connections) = config.min_connections {
+        options = options.min_connections(min_connections);
+    }
+    if let Some(timeout) = config.connect_timeout() {
+        options = options.acquire_timeout(timeout);
+    }
+    if let Some(timeout) = config.idle_timeout() {
+        options = options.idle_timeout(timeout);
+    }
+    options
+}
+
+/// Statements run on every new connection from `after_connect`: `init_sql`, then on a
+/// read-only connection the statement that enables it, last so nothing in `init_sql` can
+/// switch it back off. The pool only logs a failing `after_connect` and retries until the
+/// acquire timeout, so the first failure is kept for [`SessionSetup::connect`] to report.
+#[derive(Clone)]
+pub struct SessionSetup {
+    init_sql: Arc<Vec<String>>,
+    read_only_sql: Option<String>,
+    failure: Arc<Mutex<Option<String>>>,
+    failed: Arc<Notify>,
+}
+
+impl SessionSetup {
+    pub fn new(config: &DatabaseConfig, read_only_sql: &str) -> Self {
+        Self {
+            init_sql: Arc::new(config.init_sql.clone()),
+            read_only_sql: config.read_only.then(|| read_only_sql.to_string()),
+            failure: Arc::new(Mutex::new(None)),
+            failed: Arc::new(Notify::new()),
+        }
+    }
+
+    pub async fn run<C>(&self, connection: &mut C) -> sqlx::Result<()>
+    where
+        C: Connection,
+        for<'c> &'c mut C: Executor<'c, Database = C::Database>,
+    {
+        for (idx, statement) in self.init_sql.iter().enumerate() {
+            if let Err(e) = connection.execute(statement.as_str()).await {
+                self.fail(format!(
+                    "init_sql statement {} failed ({}): {}",
+                    idx + 1,
+                    statement,
+                    e
+                ));
+                return Err(e);
+            }
+        }
+        if let Some(statement) = &self.read_only_sql
+            && let Err(e) = connection.execute(statement.as_str()).await
+        {
+            self.fail(format!(
+                "Enabling read-only mode failed ({}): {}",
+                statement, e
+            ));
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Opens the pool and its first connection, failing as soon as setting that connection
+    /// up fails instead of when the acquire timeout runs out.
+    pub async fn connect<DB: Database>(
+        &self,
+        pool_options: PoolOptions<DB>,
+        options: <DB::Connection as Connection>::Options,
+    ) -> Result<Pool<DB>> {
+        let pool = pool_options.connect_lazy_with(options);
+        tokio::select! {
+            connection = pool.acquire() => {
+                connection.map_err(tls::connection_error)?;
+                Ok(pool)
+            }
+            _ = self.failed.notified() => {
+                pool.close().await;
+                let failure = self.failure.lock().unwrap().take();
+                Err(anyhow!(failure.unwrap_or_default()))
+            }
+        }
+    }
+
+    fn fail(&self, message: String) {
+        self.failure.lock().unwrap().get_or_insert(message);
+        self.failed.notify_one();
+    }
+}
@@ -1,18 +1,87 @@
 use super::formatting::PostgresFormatter;
+use super::introspection;
+use super::notices;
+use crate::channel_monitor::ChannelNotification;
 use crate::config::Config;
-use crate::database::{DatabaseClient, ForeignKeyInfo, QueryResult, SchemaCache};
+use crate::database::fk::infer_column_sources;
+use crate::database::notice::attach_notices;
+use crate::database::pager::{OpenResult, Page, ResultSlot, read_page};
+use crate::database::param::params_for;
+use crate::database::{
+    CatalogColumn, ColumnRef, DatabaseClient, ExecutionOutcome, ForeignKeyInfo, QueryParam,
+    QueryResult, SchemaCache, SchemaInfo, TransactionSession, Value,
+};
+use crate::database::{pool, tls};
+use crate::query_parser::QueryAnalyzer;
+use crate::query_parser::placeholder::{
+    find_placeholders, placeholder_names, replace_placeholders,
+};
+use crate::query_plan::ExplainMode;
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::PgPool;
-use sqlx::Row;
-use std::collections::HashMap;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use sqlx::pool::PoolConnection;
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::{
+    PgArguments, PgConnectOptions, PgConnection, PgListener, PgPoolOptions, PgRow,
+};
+use sqlx::query::Query;
+use sqlx::{Connection, Either, Executor, PgPool, Postgres};
+use sqlx::{Row, Statement};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
+use tracing::instrument::WithSubscriber;
 
 #[derive(Clone)]
 pub struct PostgresClient {
     pool: PgPool,
     schema_cache: SchemaCache,
     formatter: PostgresFormatter,
+    active_backends: Arc<Mutex<HashSet<i32>>>,
+    relations: Arc<Mutex<HashMap<u32, Relation>>>,
+    session: TransactionSession<Postgres>,
+    open_result: ResultSlot<PgRow>,
+}
+
+struct Relation {
+    schema: String,
+    name: String,
+    columns: HashMap<i16, String>,
+}
+
+/// Keeps a backend pid registered for cancellation while its statement runs.
+struct ActiveBackend {
+    pid: i32,
+    active_backends: Arc<Mutex<HashSet<i32>>>,
+}
+
+impl ActiveBackend {
+    /// Registers the backend `conn` is connected to. Asking fails while a transaction is
+    /// aborted, which must not block the ROLLBACK, so the statement then runs unregistered.
+    async fn register(
+        conn: &mut PgConnection,
+        active_backends: &Arc<Mutex<HashSet<i32>>>,
+    ) -> Option<Self> {
+        let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+            .fetch_one(conn)
+            .await
+            .ok()?;
+        active_backends.lock().unwrap().insert(pid);
+        Some(Self {
+            pid,
+            active_backends: Arc::clone(active_backends),
+        })
+    }
+}
+
+impl Drop for ActiveBackend {
+    fn drop(&mut self) {
+        self.active_backends.lock().unwrap().remove(&self.pid);
+    }
 }
 
 impl PostgresClient {
@@ -21,95 +90,702 @@ impl PostgresClient {
             .get_database_url()
             .ok_or_else(|| anyhow::anyhow!("No DATABASE_URL found"))?;
 
-        let pool = PgPool::connect(database_url).await?;
-        let formatter = PostgresFormatter::new();
+        let mut options = PgConnectOptions::from_str(database_url)?;
+        if let Some(schema) = &config.database.default_schema {
+            // Keep `public` reachable so extension functions installed there still resolve.
+            // Startup options are space-separated, so spaces in the value must be escaped.
+            let search_path = format!("{},public", quote_identifier(schema))
+                .replace('\\', "\\\\")
+                .replace(' ', "\\ ");
+            options = options.options([("search_path", search_path)]);
+        }
+        let tls = &config.database.tls;
+        tls.validate()?;
+        if let Some(mode) = tls.sslmode {
+            options = options.ssl_mode(tls::pg_ssl_mode(mode));
+        }
+        if let Some(path) = &tls.sslrootcert {
+            options = options.ssl_root_cert(path);
+        }
+        if let Some(path) = &tls.sslcert {
+            options = options.ssl_client_cert(path);
+        }
+        if let Some(path) = &tls.sslkey {
+            options = options.ssl_client_key(path);
+        }
+        let timeouts: Vec<(&'static str, String)> = [
+            ("statement_timeout", &config.database.statement_timeout),
+            ("lock_timeout", &config.database.lock_timeout),
+        ]
+        .into_iter()
+        .filter_map(|(setting, value)| Some((setting, value.clone()?)))
+        .collect();
+        for (setting, value) in &timeouts {
+            check_timeout(setting, value)?;
+        }
+        let setup =
+            pool::SessionSetup::new(&config.database, "SET default_transaction_read_only = on");
+        let pool_options = pool::pool_options::<Postgres>(&config.database.pool).after_connect({
+            let setup = setup.clone();
+            move |connection, _| {
+                let timeouts = timeouts.clone();
+                let setup = setup.clone();
+                Box::pin(async move {
+                    for (setting, value) in timeouts {
+                        sqlx::query("SELECT set_config($1, $2, false)")
+                            .bind(setting)
+                            .bind(value)
+                            .execute(&mut *connection)
+                            .await?;
+                    }
+                    setup.run(connection).await
+                })
+            }
+        });
+        let pool = setup.connect(pool_options, options).await?;
+        // MONEY has as many fractional digits as `lc_monetary` says, which casting shows.
+        let money_digits: i32 = sqlx::query_scalar("SELECT scale(0::money::numeric)")
+            .fetch_one(&pool)
+            .await?;
+        let formatter = PostgresFormatter::new().with_money_digits(u32::try_from(money_digits)?);
 
         let mut client = Self {
             pool,
             schema_cache: SchemaCache::new(),
             formatter,
+            active_backends: Arc::new(Mutex::new(HashSet::new())),
+            relations: Arc::new(Mutex::new(HashMap::new())),
+            session: TransactionSession::new(),
+            open_result: ResultSlot::new(),
         };
 
         if config.foreign_keys.enabled {
-            client.schema_cache = client.analyze_schema().await?;
+            client.schema_cache = client.analyze_schema(&config.foreign_keys.schemas).await?;
+            if !config.foreign_keys.manual_mapping.is_empty() {
+                let columns = client.catalog_columns(&config.foreign_keys.schemas).await?;
+                client
+                    .schema_cache
+                    .apply_manual_mapping(&config.foreign_keys.manual_mapping, &columns);
+            }
         }
 
         Ok(client)
     }
 
-    async fn analyze_schema(&self) -> Result<HashMap<String, Vec<ForeignKeyInfo>>> {
-        let mut schema_map: HashMap<String, Vec<ForeignKeyInfo>> = HashMap::new();
+    async fn run_query(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        row_limit: usize,
+    ) -> Result<QueryResult> {
+        let start_time = Instant::now();
+        // The viewer is about to show a new result, so the last one's rows are not needed.
+        self.open_result.close();
+        let analyzer = QueryAnalyzer::new();
+        let command_tag = analyzer.command_tag(query);
+        let control = analyzer.transaction_control(query);
+        // Blocks such as `BEGIN; ...; COMMIT` cannot be prepared, so they go over the simple
+        // protocol, which returns values as text.
+        let simple = params.is_empty() && analyzer.is_multi_statement(query, false);
+        let mut session = self.session.checkout(&self.pool, control).await?;
+        // Statements with side effects run to completion so the count is right, and a pinned
+        // connection has to stay usable for the rest of the transaction.
+        let drain = command_tag.is_some() || session.is_pinned();
+
+        let (fetched, column_sources) = if drain || control.is_some() {
+            let conn = session.connection();
+            let fetched = async {
+                let _active = ActiveBackend::register(conn, &self.active_backends).await;
+                let (statement, values) = prepare(conn, query, params, simple).await?;
+                let mut stream = fetch_rows(conn, &statement, values, simple);
+                let page = read_page(&mut stream, &mut None, row_limit, drain).await?;
+                drop(stream);
+                if page.more && !drain {
+                    conn.close_on_drop();
+                }
+                anyhow::Ok(page)
+            }
+            .await;
+            // The session's own connection sees relations created in an open transaction.
+            let origins = fetched.as_ref().map(row_origins).unwrap_or_default();
+            let mut column_sources = None;
+            if origins.iter().any(Option::is_some) {
+                column_sources = self
+                    .resolve_column_origins(session.connection(), &origins)
+                    .await
+                    .ok();
+            }
+            session.finish(fetched.is_ok());
+            (fetched?, column_sources)
+        } else {
+            let conn = session
+                .take_pooled()
+                .expect("an unpinned checkout holds a pooled connection");
+            let page = self
+                .open_query(conn, query, params, simple, row_limit)
+                .await?;
+            // The result's own connection is busy until it is read to the end, so the lookup
+            // needs another one, and is skipped rather than waiting for it.
+            let origins = row_origins(&page);
+            let mut column_sources = None;
+            if origins.iter().any(Option::is_some)
+                && let Some(mut conn) = self.pool.try_acquire()
+            {
+                column_sources = self.resolve_column_origins(&mut conn, &origins).await.ok();
+            }
+            (page, column_sources)
+        };
+
+        let mut result = self
+            .formatter
+            .rows_to_query_result(fetched.rows, start_time)?;
+        result.truncated = fetched.more;
+        result.outcome = command_tag.map(|command_tag| ExecutionOutcome {
+            command_tag,
+            rows_affected: fetched.rows_affected,
+        });
+        // The catalog lookup is best-effort: the statement has already run either way.
+        result.column_sources = column_sources
+            .unwrap_or_else(|| infer_column_sources(&self.schema_cache, query, &result.headers));
+        Ok(result)
+    }
+
+    /// Reads the first page of a query on a task that keeps the rest of its result open on
+    /// `conn`, so more rows can be read later without running it again.
+    async fn open_query(
+        &self,
+        mut conn: PoolConnection<Postgres>,
+        query: &str,
+        params: &[QueryParam],
+        simple: bool,
+        row_limit: usize,
+    ) -> Result<Page<PgRow>> {
+        let active = ActiveBackend::register(&mut conn, &self.active_backends).await;
+        let (statement, values) = prepare(&mut conn, query, params, simple).await?;
+        let (open, requests) = OpenResult::new();
+        tokio::spawn(
+            async move {
+                let _active = active;
+                let mut stream = fetch_rows(&mut conn, &statement, values, simple);
+                let unread = requests.serve(&mut stream).await;
+                drop(stream);
+                if unread {
+                    // The server keeps sending the remaining rows, so closing the connection
+                    // is cheaper than draining them before it can be reused.
+                    conn.close_on_drop();
+                }
+            }
+            .with_current_subscriber(),
+        );
+        let page = open.next_page(row_limit).await?;
+        if page.more {
+            self.open_result.keep(open);
+        }
+        Ok(page)
+    }
+
+    async fn analyze_schema(&self, schemas: &[String]) -> Result<SchemaCache> {
+        let mut schema_cache = SchemaCache::new();
         let query = r#"
-            SELECT 
-                kcu.column_name, 
-                ccu.table_name AS foreign_table_name, 
-                ccu.column_name AS foreign_column_name
-            FROM 
-                information_schema.table_constraints AS tc 
-                JOIN information_schema.key_column_usage AS kcu
-                  ON tc.constraint_name = kcu.constraint_name
-                  AND tc.table_schema = kcu.table_schema
-                JOIN information_schema.constraint_column_usage AS ccu
-                  ON ccu.constraint_name = tc.constraint_name
-                  AND ccu.table_schema = tc.table_schema
-            WHERE tc.constraint_type = 'FOREIGN KEY' AND tc.table_schema = 'public';
+            SELECT
+                con.conname::text AS constraint_name,
+                sn.nspname::text AS table_schema,
+                sc.relname::text AS table_name,
+                array_agg(sa.attname::text ORDER BY k.ord) AS column_names,
+                tn.nspname::text AS foreign_table_schema,
+                tc.relname::text AS foreign_table_name,
+                array_agg(ta.attname::text ORDER BY k.ord) AS foreign_column_names
+            FROM
+                pg_constraint AS con
+                CROSS JOIN LATERAL unnest(con.conkey, con.confkey)
+                    WITH ORDINALITY AS k(conkey, confkey, ord)
+                JOIN pg_class AS sc ON sc.oid = con.conrelid
+                JOIN pg_namespace AS sn ON sn.oid = sc.relnamespace
+                JOIN pg_attribute AS sa ON sa.attrelid = con.conrelid AND sa.attnum = k.conkey
+                JOIN pg_class AS tc ON tc.oid = con.confrelid
+                JOIN pg_namespace AS tn ON tn.oid = tc.relnamespace
+                JOIN pg_attribute AS ta ON ta.attrelid = con.confrelid AND ta.attnum = k.confkey
+            WHERE con.contype = 'f'
+                AND sn.nspname = ANY(COALESCE($1, current_schemas(false)::text[]))
+            GROUP BY con.oid, con.conname, sn.nspname, sc.relname, tn.nspname, tc.relname;
         "#;
 
-        let rows = sqlx::query(query).fetch_all(&self.pool).await?;
+        let schemas = (!schemas.is_empty()).then_some(schemas);
+        let rows = sqlx::query(query)
+            .bind(schemas)
+            .fetch_all(&self.pool)
+            .await?;
 
         for row in rows {
-            let fk_info = ForeignKeyInfo {
-                column_name: row.get("column_name"),
+            schema_cache.insert(ForeignKeyInfo {
+                constraint_name: row.get("constraint_name"),
+                schema: row.get("table_schema"),
+                table_name: row.get("table_name"),
+                columns: row.get("column_names"),
+                referenced_schema: row.get("foreign_table_schema"),
                 referenced_table: row.get("foreign_table_name"),
-                referenced_column: row.get("foreign_column_name"),
-            };
-            schema_map
-                .entry(fk_info.column_name.clone())
-                .or_default()
-                .push(fk_info);
+                referenced_columns: row.get("foreign_column_names"),
+            });
         }
 
-        Ok(schema_map)
+        Ok(schema_cache)
     }
+
+    /// Every column in the scanned schemas, for resolving manual foreign key mappings.
+    async fn catalog_columns(&self, schemas: &[String]) -> Result<Vec<CatalogColumn>> {
+        let query = r#"
+            SELECT table_schema::text, table_name::text, column_name::text
+            FROM information_schema.columns
+            WHERE table_schema = ANY(COALESCE($1, current_schemas(false)::text[]))
+            ORDER BY table_schema, table_name, ordinal_position;
+        "#;
+
+        let schemas = (!schemas.is_empty()).then_some(schemas);
+        let rows = sqlx::query(query)
+            .bind(schemas)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| (row.get(0), row.get(1), row.get(2)))
+            .collect())
+    }
+
+    /// Maps (table OID, attribute number) pairs reported by the server to column names,
+    /// loading any relations not seen before over `conn`.
+    async fn resolve_column_origins(
+        &self,
+        conn: &mut PgConnection,
+        origins: &[Option<(u32, i16)>],
+    ) -> Result<Vec<Option<ColumnRef>>> {
+        let missing: Vec<i64> = {
+            let relations = self.relations.lock().unwrap();
+            origins
+                .iter()
+                .flatten()
+                .map(|(oid, _)| *oid)
+                .filter(|oid| !relations.contains_key(oid))
+                .map(i64::from)
+                .collect()
+        };
+
+        if !missing.is_empty() {
+            let rows = sqlx::query(
+                r#"
+                SELECT c.oid::int8 AS oid, n.nspname::text AS schema, c.relname::text AS name,
+                       a.attnum, a.attname::text AS column_name
+                FROM pg_class AS c
+                    JOIN pg_namespace AS n ON n.oid = c.relnamespace
+                    JOIN pg_attribute AS a ON a.attrelid = c.oid
+                WHERE c.oid::int8 = ANY($1) AND a.attnum > 0 AND NOT a.attisdropped
+                "#,
+            )
+            .bind(&missing)
+            .fetch_all(&mut *conn)
+            .await?;
+
+            let mut relations = self.relations.lock().unwrap();
+            for row in rows {
+                let oid = row.get::<i64, _>("oid") as u32;
+                let relation = relations.entry(oid).or_insert_with(|| Relation {
+                    schema: row.get("schema"),
+                    name: row.get("name"),
+                    columns: HashMap::new(),
+                });
+                relation
+                    .columns
+                    .insert(row.get("attnum"), row.get("column_name"));
+            }
+        }
+
+        let relations = self.relations.lock().unwrap();
+        Ok(origins
+            .iter()
+            .map(|origin| {
+                let (oid, attnum) = (*origin)?;
+                let relation = relations.get(&oid)?;
+                let column = relation.columns.get(&attnum)?;
+                Some(ColumnRef::new(
+                    Some(&relation.schema),
+                    &relation.name,
+                    column,
+                ))
+            })
+            .collect())
+    }
+}
+
+/// Rejects values the server would refuse, since the pool retries a failing `after_connect`
+/// until it times out instead of reporting why.
+fn check_timeout(setting: &str, value: &str) -> Result<()> {
+    let value = value.trim();
+    let number_end = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(number_end);
+    if number.parse::<f64>().is_ok()
+        && ["", "us", "ms", "s", "min", "h", "d"].contains(&unit.trim())
+    {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "Invalid {} '{}': expected milliseconds or a duration such as '30s'",
+            setting,
+            value
+        ))
+    }
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// `schema.table WHERE c1 = .. AND c2 = ..`, with one placeholder per key column.
+fn key_filter(schema: &str, table: &str, columns: &[String]) -> String {
+    let conditions = columns
+        .iter()
+        .enumerate()
+        .map(|(idx, column)| format!("{} = ${}", quote_identifier(column), idx + 1))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    format!(
+        "{}.{} WHERE {}",
+        quote_identifier(schema),
+        quote_identifier(table),
+        conditions
+    )
+}
+
+/// The table and column each result column was read from, as far as the server reports it.
+fn row_origins(page: &Page<PgRow>) -> Vec<Option<(u32, i16)>> {
+    page.rows
+        .first()
+        .map(|row| {
+            row.columns()
+                .iter()
+                .map(|column| Some((column.relation_id()?.0, column.relation_attribute_no()?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The statement to run for `query` and the values to bind to it. A `simple` block is sent
+/// as it is.
+async fn prepare(
+    conn: &mut PgConnection,
+    query: &str,
+    params: &[QueryParam],
+    simple: bool,
+) -> Result<(String, Vec<Option<String>>)> {
+    if simple || params.is_empty() {
+        Ok((query.to_string(), Vec::new()))
+    } else {
+        typed_placeholders(conn, query, params).await
+    }
+}
+
+fn fetch_rows<'c>(
+    conn: &'c mut PgConnection,
+    statement: &'c str,
+    values: Vec<Option<String>>,
+    simple: bool,
+) -> BoxStream<'c, sqlx::Result<Either<u64, PgRow>>> {
+    let stream = if simple {
+        sqlx::raw_sql(statement).fetch_many(conn)
+    } else {
+        let mut bound = sqlx::query(statement);
+        for value in values {
+            bound = bound.bind(value);
+        }
+        conn.fetch_many(bound)
+    };
+    stream
+        .map_ok(|item| item.map_left(|done| done.rows_affected()))
+        .boxed()
+}
+
+fn bind_value<'q>(
+    query: Query<'q, Postgres, PgArguments>,
+    value: &Value,
+) -> Query<'q, Postgres, PgArguments> {
+    match value {
+        Value::Null => query.bind(None::<String>),
+        Value::Bool(val) => query.bind(*val),
+        Value::Int(val) => query.bind(*val),
+        Value::Float(val) => query.bind(*val),
+        Value::Decimal(val) | Value::Text(val) | Value::Other(val) => query.bind(val.clone()),
+        Value::Bytes(val) => query.bind(val.clone()),
+        Value::Json(val) => query.bind(sqlx::types::Json(val.clone())),
+        Value::Date(val) => query.bind(*val),
+        Value::Time(val) => query.bind(*val),
+        Value::Timestamp(val) => query.bind(*val),
+        Value::TimestampTz(val) => query.bind(*val),
+        Value::Uuid(val) => query.bind(*val),
+    }
+}
+
+/// Rewrites placeholders as `$n` and returns the values to bind in order. Each value is sent
+/// as text and cast to the type the server infers for its position, so `:day` works against
+/// a `date` column just as `:name` does against `text`.
+async fn typed_placeholders(
+    conn: &mut PgConnection,
+    query: &str,
+    params: &[QueryParam],
+) -> Result<(String, Vec<Option<String>>)> {
+    let placeholders = find_placeholders(query);
+    let values = params_for(&placeholder_names(&placeholders), params)?
+        .into_iter()
+        .map(|param| param.text().map(str::to_string))
+        .collect();
+
+    let numbered =
+        replace_placeholders(query, &placeholders, |position, _| format!("${}", position));
+    // A failed prepare has already aborted an open transaction, so its error is the one to
+    // report rather than whatever running the statement would say next.
+    let statement = (&mut *conn).prepare(&numbered).await?;
+    let types: Vec<Option<String>> = match statement.parameters() {
+        Some(Either::Left(parameters)) => {
+            let oids: Vec<Option<Oid>> =
+                parameters.iter().map(|parameter| parameter.oid()).collect();
+            sqlx::query_scalar(
+                "SELECT format_type(t, NULL) \
+                 FROM unnest($1::oid[]) WITH ORDINALITY AS p(t, n) ORDER BY n",
+            )
+            .bind(oids)
+            .fetch_all(&mut *conn)
+            .await?
+        }
+        _ => Vec::new(),
+    };
+
+    let statement = replace_placeholders(query, &placeholders, |position, _| {
+        match types.get(position - 1) {
+            Some(Some(type_name)) if type_name != "text" => {
+                format!("(${}::text::{})", position, type_name)
+            }
+            _ => format!("${}", position),
+        }
+    });
+    Ok((statement, values))
 }
 
 #[async_trait]
 impl DatabaseClient for PostgresClient {
-    async fn execute_query(&self, query: &str) -> Result<QueryResult> {
-        let start_time = Instant::now();
-        let rows = sqlx::query(query).fetch_all(&self.pool).await?;
-        self.formatter.rows_to_query_result(rows, start_time)
+    async fn execute_query(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        row_limit: usize,
+    ) -> Result<QueryResult> {
+        let (result, notices) = notices::collect(self.run_query(query, params, row_limit)).await;
+        match result {
+            Ok(mut result) => {
+                result.notices = notices;
+                Ok(result)
+            }
+            Err(e) => Err(attach_notices(e, notices)),
+        }
     }
 
-    async fn lookup_foreign_key(&self, column_name: &str, value: &str) -> Result<QueryResult> {
-        let fk_info = self.get_foreign_key_info(column_name)?;
+    async fn explain(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        mode: ExplainMode,
+    ) -> Result<serde_json::Value> {
+        let mut session = self.session.checkout(&self.pool, None).await?;
+        // Inside an open transaction a savepoint undoes the statement without ending it.
+        let (begin, rollback) = if session.is_pinned() {
+            (
+                "SAVEPOINT sqlk_explain",
+                "ROLLBACK TO SAVEPOINT sqlk_explain; RELEASE SAVEPOINT sqlk_explain",
+            )
+        } else {
+            ("BEGIN", "ROLLBACK")
+        };
+        let conn = session.connection();
+        let _active = ActiveBackend::register(conn, &self.active_backends).await;
+
+        let (statement, values) = if params.is_empty() {
+            (query.to_string(), Vec::new())
+        } else {
+            typed_placeholders(conn, query, params).await?
+        };
+        let options = match mode {
+            ExplainMode::Estimate => "FORMAT JSON",
+            ExplainMode::Analyze => "ANALYZE, BUFFERS, FORMAT JSON",
+        };
+        let explain = format!("EXPLAIN ({}) {}", options, statement);
+        let mut bound = sqlx::query_scalar::<_, serde_json::Value>(&explain);
+        for value in values {
+            bound = bound.bind(value);
+        }
 
+        if mode == ExplainMode::Estimate {
+            return Ok(bound.fetch_one(&mut **conn).await?);
+        }
+        // A pooled connection has to be covered while BEGIN runs. The user's transaction is
+        // only owed the rollback once the savepoint exists, as a failed SAVEPOINT leaves
+        // nothing to undo.
+        if !session.is_pinned() {
+            session.set_undo_on_drop(Some(rollback));
+        }
+        let conn = session.connection();
+        (&mut **conn).execute(begin).await?;
+        session.set_undo_on_drop(Some(rollback));
+        let conn = session.connection();
+        let plan = bound.fetch_one(&mut **conn).await;
+        // Roll back even when EXPLAIN failed, so the changes and the error state are undone.
+        (&mut **conn).execute(rollback).await?;
+        session.set_undo_on_drop(None);
+        Ok(plan?)
+    }
+
+    async fn listen(
+        &self,
+        channels: &[String],
+    ) -> Result<BoxStream<'static, Result<ChannelNotification>>> {
+        // A pool of its own keeps the listener from holding one of the query connections
+        // for as long as it runs, while connecting with the same options.
+        let options = PgConnectOptions::clone(&self.pool.connect_options());
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .map_err(tls::connection_error)?;
+        let mut listener = PgListener::connect_with(&pool).await?;
+        listener
+            .listen_all(channels.iter().map(String::as_str))
+            .await?;
+        Ok(listener
+            .into_stream()
+            .map(|notification| {
+                let notification = notification?;
+                Ok(ChannelNotification::new(
+                    notification.channel().to_string(),
+                    notification.payload().to_string(),
+                    notification.process_id(),
+                ))
+            })
+            .boxed())
+    }
+
+    fn cancel_query(&self) -> BoxFuture<'static, Result<()>> {
+        let pids: Vec<i32> = self
+            .active_backends
+            .lock()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        let options = PgConnectOptions::clone(&self.pool.connect_options());
+        Box::pin(async move {
+            if pids.is_empty() {
+                return Ok(());
+            }
+            // A connection of its own, so the cancel does not wait for a free one in the pool.
+            let mut conn = PgConnection::connect_with(&options)
+                .await
+                .map_err(tls::connection_error)?;
+            sqlx::query("SELECT pg_cancel_backend(pid) FROM unnest($1::int4[]) AS pid")
+                .bind(pids)
+                .execute(&mut conn)
+                .await?;
+            conn.close().await?;
+            Ok(())
+        })
+    }
+
+    async fn fetch_more(&self, row_limit: usize) -> Result<QueryResult> {
+        let start_time = Instant::now();
+        let page = self.open_result.next_page(row_limit).await?;
+        let mut result = self.formatter.rows_to_query_result(page.rows, start_time)?;
+        result.truncated = page.more;
+        Ok(result)
+    }
+
+    async fn fetch_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+        limit: usize,
+    ) -> Result<QueryResult> {
         let query_string = format!(
-            "SELECT * FROM \"{}\" WHERE \"{}\" = $1 LIMIT 10",
-            fk_info.referenced_table, fk_info.referenced_column
+            "SELECT * FROM {} LIMIT {}",
+            key_filter(schema, table, columns),
+            limit + 1
         );
 
+        let mut session = self.session.checkout(&self.pool, None).await?;
         let start_time = Instant::now();
+        let mut rows = values
+            .iter()
+            .fold(sqlx::query(&query_string), bind_value)
+            .fetch_all(&mut **session.connection())
+            .await?;
+        let truncated = rows.len() > limit;
+        rows.truncate(limit);
 
-        let rows = if let Ok(int_value) = value.parse::<i64>() {
-            sqlx::query(&query_string)
-                .bind(int_value)
-                .fetch_all(&self.pool)
-                .await?
-        } else {
-            sqlx::query(&query_string)
-                .bind(value)
-                .fetch_all(&self.pool)
-                .await?
-        };
+        let mut result = self.formatter.rows_to_query_result(rows, start_time)?;
+        result.truncated = truncated;
+        result.column_sources = ColumnRef::for_table(schema, table, &result.headers);
+        Ok(result)
+    }
+
+    async fn count_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+    ) -> Result<i64> {
+        let query_string = format!(
+            "SELECT COUNT(*) FROM {}",
+            key_filter(schema, table, columns)
+        );
+        let mut session = self.session.checkout(&self.pool, None).await?;
+        let count = values
+            .iter()
+            .fold(sqlx::query(&query_string), bind_value)
+            .fetch_one(&mut **session.connection())
+            .await?
+            .try_get(0)?;
+        Ok(count)
+    }
+
+    fn get_foreign_key_info(&self, column: &ColumnRef) -> Option<&ForeignKeyInfo> {
+        self.schema_cache.get(column)
+    }
+
+    fn get_referencing_foreign_keys(
+        &self,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Vec<&ForeignKeyInfo> {
+        self.schema_cache.referencing(schema, table)
+    }
+
+    fn foreign_key_warnings(&self) -> &[String] {
+        self.schema_cache.warnings()
+    }
+
+    async fn introspect_schema(&self) -> Result<Vec<SchemaInfo>> {
+        introspection::introspect_schema(&self.pool).await
+    }
 
-        self.formatter.rows_to_query_result(rows, start_time)
+    fn preview_query(&self, schema: &str, table: &str, limit: usize) -> String {
+        format!(
+            "SELECT * FROM {}.{} LIMIT {}",
+            quote_identifier(schema),
+            quote_identifier(table),
+            limit
+        )
     }
 
-    fn get_foreign_key_info(&self, column_name: &str) -> Result<&ForeignKeyInfo> {
-        self.schema_cache
-            .get(column_name)
-            .and_then(|fks| fks.first())
-            .ok_or_else(|| anyhow::anyhow!("No foreign key info for column: {}", column_name))
+    fn in_transaction(&self) -> bool {
+        self.session.is_active()
     }
 }
@@ -0,0 +1,1061 @@
+use anyhow::{Result, anyhow, bail};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use sqlx::postgres::{PgTypeInfo, PgTypeKind};
+use std::fmt::Write;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::database::Value;
+
+/// Object ids of the built-in types, which are fixed by `pg_type.dat`.
+mod oid {
+    pub const BOOL: u32 = 16;
+    pub const BYTEA: u32 = 17;
+    pub const CHAR: u32 = 18;
+    pub const NAME: u32 = 19;
+    pub const INT8: u32 = 20;
+    pub const INT2: u32 = 21;
+    pub const INT2VECTOR: u32 = 22;
+    pub const INT4: u32 = 23;
+    pub const REGPROC: u32 = 24;
+    pub const TEXT: u32 = 25;
+    pub const OID: u32 = 26;
+    pub const TID: u32 = 27;
+    pub const XID: u32 = 28;
+    pub const CID: u32 = 29;
+    pub const OIDVECTOR: u32 = 30;
+    pub const JSON: u32 = 114;
+    pub const XML: u32 = 142;
+    pub const POINT: u32 = 600;
+    pub const LSEG: u32 = 601;
+    pub const PATH: u32 = 602;
+    pub const BOX: u32 = 603;
+    pub const POLYGON: u32 = 604;
+    pub const LINE: u32 = 628;
+    pub const CIDR: u32 = 650;
+    pub const FLOAT4: u32 = 700;
+    pub const FLOAT8: u32 = 701;
+    pub const UNKNOWN: u32 = 705;
+    pub const CIRCLE: u32 = 718;
+    pub const MACADDR8: u32 = 774;
+    pub const MONEY: u32 = 790;
+    pub const MACADDR: u32 = 829;
+    pub const INET: u32 = 869;
+    pub const BPCHAR: u32 = 1042;
+    pub const VARCHAR: u32 = 1043;
+    pub const DATE: u32 = 1082;
+    pub const TIME: u32 = 1083;
+    pub const TIMESTAMP: u32 = 1114;
+    pub const TIMESTAMPTZ: u32 = 1184;
+    pub const INTERVAL: u32 = 1186;
+    pub const TIMETZ: u32 = 1266;
+    pub const BIT: u32 = 1560;
+    pub const VARBIT: u32 = 1562;
+    pub const NUMERIC: u32 = 1700;
+    pub const REFCURSOR: u32 = 1790;
+    pub const REGPROCEDURE: u32 = 2202;
+    pub const REGOPER: u32 = 2203;
+    pub const REGOPERATOR: u32 = 2204;
+    pub const REGCLASS: u32 = 2205;
+    pub const REGTYPE: u32 = 2206;
+    pub const RECORD: u32 = 2249;
+    pub const CSTRING: u32 = 2275;
+    pub const VOID: u32 = 2278;
+    pub const UUID: u32 = 2950;
+    pub const TXID_SNAPSHOT: u32 = 2970;
+    pub const PG_LSN: u32 = 3220;
+    pub const TSVECTOR: u32 = 3614;
+    pub const TSQUERY: u32 = 3615;
+    pub const REGCONFIG: u32 = 3734;
+    pub const REGDICTIONARY: u32 = 3769;
+    pub const JSONB: u32 = 3802;
+    pub const JSONPATH: u32 = 4072;
+    pub const REGNAMESPACE: u32 = 4089;
+    pub const REGROLE: u32 = 4096;
+    pub const REGCOLLATION: u32 = 4191;
+    pub const PG_SNAPSHOT: u32 = 5038;
+    pub const XID8: u32 = 5069;
+}
+
+/// Built-in range types and their element types.
+const RANGES: &[(u32, u32)] = &[
+    (3904, oid::INT4),
+    (3906, oid::NUMERIC),
+    (3908, oid::TIMESTAMP),
+    (3910, oid::TIMESTAMPTZ),
+    (3912, oid::DATE),
+    (3926, oid::INT8),
+];
+
+/// Built-in multirange types and the range types they hold.
+const MULTIRANGES: &[(u32, u32)] = &[
+    (4451, 3904),
+    (4532, 3906),
+    (4533, 3908),
+    (4534, 3910),
+    (4535, 3912),
+    (4536, 3926),
+];
+
+/// Array types that may turn up inside anonymous records, where only an oid is known.
+const ARRAYS: &[u32] = &[
+    143, 199, 651, 791, 1000, 1001, 1002, 1003, 1005, 1007, 1009, 1014, 1015, 1016, 1021, 1022,
+    1028, 1040, 1041, 1115, 1182, 1183, 1185, 1187, 1231, 1270, 1563, 2951, 3807,
+];
+
+const POSTGRES_EPOCH: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+
+/// Decodes binary-format values with the settings of the session they were read from.
+#[derive(Clone)]
+pub struct Decoder {
+    /// Fractional digits of MONEY, which follow the session's `lc_monetary`.
+    money_digits: u32,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder {
+    pub fn new() -> Self {
+        Self { money_digits: 2 }
+    }
+
+    pub fn with_money_digits(mut self, money_digits: u32) -> Self {
+        self.money_digits = money_digits;
+        self
+    }
+
+    /// Decodes a binary-format value into the closest [`Value`]. Domains decode as their base
+    /// type and enums as their label; anything without a dedicated variant is rendered the way
+    /// `psql` would print it.
+    pub fn decode_value(&self, type_info: &PgTypeInfo, bytes: &[u8]) -> Result<Value> {
+        let type_oid = type_info.oid().map(|type_oid| type_oid.0);
+        match type_info.kind() {
+            PgTypeKind::Domain(base) => self.decode_value(base, bytes),
+            PgTypeKind::Enum(_) => Ok(Value::Text(text(bytes)?)),
+            PgTypeKind::Composite(fields) => {
+                let field_types: Vec<&PgTypeInfo> = fields.iter().map(|(_, ty)| ty).collect();
+                Ok(Value::Other(
+                    self.render_composite(bytes, Some(&field_types))?,
+                ))
+            }
+            // The catalog files the vector types as arrays, but they print space-separated.
+            PgTypeKind::Array(_) if matches!(type_oid, Some(oid::INT2VECTOR | oid::OIDVECTOR)) => {
+                self.decode_oid(type_oid.unwrap_or_default(), bytes)
+            }
+            PgTypeKind::Array(element) => {
+                Ok(Value::Other(render_array(bytes, &|element_oid, bytes| {
+                    self.render_element(Some(element), element_oid, bytes)
+                })?))
+            }
+            PgTypeKind::Range(subtype) => Ok(Value::Other(render_range(bytes, &|bytes| {
+                self.render(subtype, bytes)
+            })?)),
+            PgTypeKind::Simple | PgTypeKind::Pseudo => match type_oid {
+                Some(type_oid) => self.decode_oid(type_oid, bytes),
+                None => Ok(fallback(bytes)),
+            },
+        }
+    }
+
+    fn render(&self, type_info: &PgTypeInfo, bytes: &[u8]) -> Result<String> {
+        self.decode_value(type_info, bytes)
+            .map(|value| value.to_string())
+    }
+
+    /// Arrays carry their element oid, but user-defined elements need the resolved type info.
+    fn render_element(
+        &self,
+        element: Option<&PgTypeInfo>,
+        element_oid: u32,
+        bytes: &[u8],
+    ) -> Result<String> {
+        match element {
+            Some(element) if !matches!(element.kind(), PgTypeKind::Simple) => {
+                self.render(element, bytes)
+            }
+            _ => self
+                .decode_oid(element_oid, bytes)
+                .map(|value| value.to_string()),
+        }
+    }
+
+    fn decode_oid(&self, type_oid: u32, bytes: &[u8]) -> Result<Value> {
+        let mut reader = Reader::new(bytes);
+        let value = match type_oid {
+            oid::BOOL => Value::Bool(reader.u8()? != 0),
+            oid::INT2 => Value::Int(reader.i16()?.into()),
+            oid::INT4 => Value::Int(reader.i32()?.into()),
+            oid::INT8 => Value::Int(reader.i64()?),
+            oid::OID
+            | oid::XID
+            | oid::CID
+            | oid::REGPROC
+            | oid::REGPROCEDURE
+            | oid::REGOPER
+            | oid::REGOPERATOR
+            | oid::REGCLASS
+            | oid::REGTYPE
+            | oid::REGCONFIG
+            | oid::REGDICTIONARY
+            | oid::REGNAMESPACE
+            | oid::REGROLE
+            | oid::REGCOLLATION => Value::Int(reader.u32()?.into()),
+            oid::XID8 => Value::Decimal(reader.u64()?.to_string()),
+            oid::FLOAT4 => Value::from(reader.f32()?),
+            oid::FLOAT8 => Value::Float(reader.f64()?),
+            oid::NUMERIC => Value::Decimal(render_numeric(&mut reader)?),
+            oid::MONEY => Value::Decimal(render_money(reader.i64()?, self.money_digits)),
+            oid::TEXT
+            | oid::VARCHAR
+            | oid::BPCHAR
+            | oid::NAME
+            | oid::XML
+            | oid::UNKNOWN
+            | oid::REFCURSOR
+            | oid::CSTRING => Value::Text(text(bytes)?),
+            oid::CHAR => Value::Text(String::from_utf8_lossy(bytes).into_owned()),
+            oid::BYTEA => Value::Bytes(bytes.to_vec()),
+            oid::UUID => Value::Uuid(uuid::Uuid::from_slice(bytes)?),
+            oid::JSON => json(bytes)?,
+            oid::JSONB => {
+                reader.version()?;
+                json(reader.rest())?
+            }
+            oid::JSONPATH => {
+                reader.version()?;
+                Value::Text(text(reader.rest())?)
+            }
+            oid::DATE => match reader.i32()? {
+                i32::MAX => Value::Other("infinity".to_string()),
+                i32::MIN => Value::Other("-infinity".to_string()),
+                days => Value::Date(
+                    POSTGRES_EPOCH
+                        .checked_add_signed(TimeDelta::days(days.into()))
+                        .ok_or_else(|| anyhow!("date out of range"))?,
+                ),
+            },
+            oid::TIME => match reader.i64()? {
+                // 24:00:00 is a valid TIME that chrono cannot represent.
+                86_400_000_000 => Value::Other("24:00:00".to_string()),
+                micros => Value::Time(time_from_micros(micros)?),
+            },
+            oid::TIMESTAMP => match timestamp_from_micros(reader.i64()?)? {
+                Ok(timestamp) => Value::Timestamp(timestamp),
+                Err(infinity) => Value::Other(infinity.to_string()),
+            },
+            oid::TIMESTAMPTZ => match timestamp_from_micros(reader.i64()?)? {
+                Ok(timestamp) => Value::TimestampTz(timestamp.and_utc()),
+                Err(infinity) => Value::Other(infinity.to_string()),
+            },
+            oid::TIMETZ => Value::Other(render_timetz(&mut reader)?),
+            oid::INTERVAL => Value::Other(render_interval(&mut reader)?),
+            oid::INET | oid::CIDR => Value::Other(render_inet(&mut reader)?),
+            oid::MACADDR | oid::MACADDR8 => Value::Other(
+                bytes
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<Vec<_>>()
+                    .join(":"),
+            ),
+            oid::BIT | oid::VARBIT => Value::Other(render_bits(&mut reader)?),
+            oid::POINT => Value::Other(render_points(&mut reader, 1)?),
+            oid::LSEG => Value::Other(format!("[{}]", render_points(&mut reader, 2)?)),
+            oid::BOX => Value::Other(render_points(&mut reader, 2)?),
+            oid::PATH => {
+                let closed = reader.u8()? != 0;
+                let count = reader.len()?;
+                let points = render_points(&mut reader, count)?;
+                Value::Other(if closed {
+                    format!("({})", points)
+                } else {
+                    format!("[{}]", points)
+                })
+            }
+            oid::POLYGON => {
+                let count = reader.len()?;
+                Value::Other(format!("({})", render_points(&mut reader, count)?))
+            }
+            oid::LINE => Value::Other(format!(
+                "{{{},{},{}}}",
+                reader.f64()?,
+                reader.f64()?,
+                reader.f64()?
+            )),
+            oid::CIRCLE => Value::Other(format!(
+                "<{},{}>",
+                render_points(&mut reader, 1)?,
+                reader.f64()?
+            )),
+            oid::TID => Value::Other(format!("({},{})", reader.u32()?, reader.u16()?)),
+            oid::PG_LSN => {
+                let lsn = reader.u64()?;
+                Value::Other(format!("{:X}/{:X}", lsn >> 32, lsn & 0xFFFF_FFFF))
+            }
+            oid::PG_SNAPSHOT | oid::TXID_SNAPSHOT => Value::Other(render_snapshot(&mut reader)?),
+            oid::TSVECTOR => Value::Other(render_tsvector(&mut reader)?),
+            oid::TSQUERY => Value::Other(render_tsquery(&mut reader)?),
+            oid::VOID => Value::Other(String::new()),
+            oid::RECORD => Value::Other(self.render_composite(bytes, None)?),
+            oid::INT2VECTOR | oid::OIDVECTOR => Value::Other(self.render_vector(&mut reader)?),
+            _ if ARRAYS.contains(&type_oid) => {
+                Value::Other(render_array(bytes, &|element_oid, bytes| {
+                    self.render_element(None, element_oid, bytes)
+                })?)
+            }
+            _ => {
+                if let Some(&(_, subtype)) = RANGES.iter().find(|(range, _)| *range == type_oid) {
+                    Value::Other(render_range(bytes, &|bytes| {
+                        self.decode_oid(subtype, bytes)
+                            .map(|value| value.to_string())
+                    })?)
+                } else if let Some(&(_, range)) = MULTIRANGES
+                    .iter()
+                    .find(|(multirange, _)| *multirange == type_oid)
+                {
+                    Value::Other(self.render_multirange(&mut reader, range)?)
+                } else {
+                    fallback(bytes)
+                }
+            }
+        };
+        Ok(value)
+    }
+
+    /// `int2vector` and `oidvector` are one-dimensional arrays printed space-separated.
+    fn render_vector(&self, reader: &mut Reader) -> Result<String> {
+        let dimensions = reader.len()?;
+        let _has_nulls = reader.i32()?;
+        let element_oid = reader.u32()?;
+        if dimensions == 0 {
+            return Ok(String::new());
+        }
+        let length = reader.len()?;
+        let _lower = reader.i32()?;
+        let elements = (0..length)
+            .map(|_| match reader.field()? {
+                Some(bytes) => self
+                    .decode_oid(element_oid, bytes)
+                    .map(|value| value.to_string()),
+                None => Ok("NULL".to_string()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(elements.join(" "))
+    }
+
+    /// Renders a row value. Named composite types bring their field types; anonymous records
+    /// only carry an oid per field.
+    fn render_composite(
+        &self,
+        bytes: &[u8],
+        field_types: Option<&[&PgTypeInfo]>,
+    ) -> Result<String> {
+        let mut reader = Reader::new(bytes);
+        let count = reader.len()?;
+        let mut fields = Vec::with_capacity(count);
+        for idx in 0..count {
+            let field_oid = reader.u32()?;
+            let field = match reader.field()? {
+                Some(bytes) => {
+                    let rendered = match field_types.and_then(|types| types.get(idx)) {
+                        Some(field_type) => self.render(field_type, bytes)?,
+                        None => self.decode_oid(field_oid, bytes)?.to_string(),
+                    };
+                    quote_composite_field(&rendered, "(),")
+                }
+                None => String::new(),
+            };
+            fields.push(field);
+        }
+        Ok(format!("({})", fields.join(",")))
+    }
+
+    fn render_multirange(&self, reader: &mut Reader, range_oid: u32) -> Result<String> {
+        let count = reader.len()?;
+        let mut ranges = Vec::with_capacity(count);
+        for _ in 0..count {
+            let length = reader.len()?;
+            ranges.push(
+                self.decode_oid(range_oid, reader.take(length)?)?
+                    .to_string(),
+            );
+        }
+        Ok(format!("{{{}}}", ranges.join(",")))
+    }
+}
+
+/// Types sqlk knows nothing about still show up: as text when they look like text,
+/// otherwise as hex.
+fn fallback(bytes: &[u8]) -> Value {
+    match std::str::from_utf8(bytes) {
+        Ok(text) if !text.chars().any(|c| c.is_control() && !c.is_whitespace()) => {
+            Value::Other(text.to_string())
+        }
+        _ => Value::Bytes(bytes.to_vec()),
+    }
+}
+
+fn text(bytes: &[u8]) -> Result<String> {
+    Ok(std::str::from_utf8(bytes)?.to_string())
+}
+
+fn json(bytes: &[u8]) -> Result<Value> {
+    Ok(serde_json::from_slice(bytes)
+        .map(Value::Json)
+        .unwrap_or_else(|_| Value::Text(String::from_utf8_lossy(bytes).into_owned())))
+}
+
+/// NUMERIC travels as base-10000 digits with a weight and a display scale, which is enough
+/// to print it exactly.
+fn render_numeric(reader: &mut Reader) -> Result<String> {
+    let ndigits = reader.i16()?;
+    let weight = i32::from(reader.i16()?);
+    let sign = reader.u16()?;
+    let dscale = usize::from(reader.u16()?);
+    let digits = (0..ndigits)
+        .map(|_| reader.i16())
+        .collect::<Result<Vec<_>>>()?;
+
+    match sign {
+        0xC000 => return Ok("NaN".to_string()),
+        0xD000 => return Ok("Infinity".to_string()),
+        0xF000 => return Ok("-Infinity".to_string()),
+        _ => {}
+    }
+    let digit = |idx: i32| {
+        usize::try_from(idx)
+            .ok()
+            .and_then(|idx| digits.get(idx))
+            .copied()
+            .unwrap_or(0)
+    };
+
+    let mut rendered = String::new();
+    if sign == 0x4000 {
+        rendered.push('-');
+    }
+    if weight < 0 {
+        rendered.push('0');
+    } else {
+        write!(rendered, "{}", digit(0))?;
+        for idx in 1..=weight {
+            write!(rendered, "{:04}", digit(idx))?;
+        }
+    }
+    if dscale > 0 {
+        let mut fraction = String::new();
+        let mut idx = weight + 1;
+        while fraction.len() < dscale {
+            write!(fraction, "{:04}", digit(idx))?;
+            idx += 1;
+        }
+        fraction.truncate(dscale);
+        rendered.push('.');
+        rendered.push_str(&fraction);
+    }
+    Ok(rendered)
+}
+
+/// MONEY is an integer count of the smallest currency unit, of which `digits` make up the
+/// fraction.
+fn render_money(amount: i64, digits: u32) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let amount = amount.unsigned_abs();
+    if digits == 0 {
+        return format!("{}{}", sign, amount);
+    }
+    let unit = 10u64.pow(digits);
+    format!(
+        "{}{}.{:0width$}",
+        sign,
+        amount / unit,
+        amount % unit,
+        width = digits as usize
+    )
+}
+
+fn time_from_micros(micros: i64) -> Result<NaiveTime> {
+    let seconds = u32::try_from(micros.div_euclid(1_000_000))?;
+    let nanos = u32::try_from(micros.rem_euclid(1_000_000))? * 1_000;
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanos)
+        .ok_or_else(|| anyhow!("time out of range"))
+}
+
+fn timestamp_from_micros(micros: i64) -> Result<std::result::Result<NaiveDateTime, &'static str>> {
+    match micros {
+        i64::MAX => Ok(Err("infinity")),
+        i64::MIN => Ok(Err("-infinity")),
+        micros => POSTGRES_EPOCH
+            .and_hms_opt(0, 0, 0)
+            .and_then(|epoch| epoch.checked_add_signed(TimeDelta::microseconds(micros)))
+            .map(Ok)
+            .ok_or_else(|| anyhow!("timestamp out of range")),
+    }
+}
+
+fn render_clock(micros: i64) -> String {
+    let sign = if micros < 0 { "-" } else { "" };
+    let micros = micros.unsigned_abs();
+    let seconds = micros / 1_000_000;
+    let mut rendered = format!(
+        "{}{:02}:{:02}:{:02}",
+        sign,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    let fraction = micros % 1_000_000;
+    if fraction > 0 {
+        rendered.push_str(format!(".{:06}", fraction).trim_end_matches('0'));
+    }
+    rendered
+}
+
+fn render_timetz(reader: &mut Reader) -> Result<String> {
+    let micros = reader.i64()?;
+    // The wire format counts seconds west of UTC, the opposite of how offsets are written.
+    let offset = -reader.i32()?;
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let mut rendered = format!("{}{}{:02}", render_clock(micros), sign, offset / 3600);
+    if offset % 3600 != 0 {
+        write!(rendered, ":{:02}", offset / 60 % 60)?;
+        if offset % 60 != 0 {
+            write!(rendered, ":{:02}", offset % 60)?;
+        }
+    }
+    Ok(rendered)
+}
+
+/// Renders an interval in the default `postgres` style, e.g. `1 year 2 mons 3 days 04:05:06`.
+fn render_interval(reader: &mut Reader) -> Result<String> {
+    let micros = reader.i64()?;
+    let days = reader.i32()?;
+    let months = reader.i32()?;
+
+    // Like PostgreSQL, mark a positive part with `+` once a negative one has been written.
+    let mut parts = Vec::new();
+    let mut after_negative = false;
+    for (amount, unit) in [(months / 12, "year"), (months % 12, "mon"), (days, "day")] {
+        if amount != 0 {
+            let sign = if after_negative && amount > 0 {
+                "+"
+            } else {
+                ""
+            };
+            let plural = if amount == 1 { "" } else { "s" };
+            parts.push(format!("{}{} {}{}", sign, amount, unit, plural));
+            after_negative |= amount < 0;
+        }
+    }
+    if micros != 0 || parts.is_empty() {
+        let sign = if after_negative && micros > 0 {
+            "+"
+        } else {
+            ""
+        };
+        parts.push(format!("{}{}", sign, render_clock(micros)));
+    }
+    Ok(parts.join(" "))
+}
+
+fn render_inet(reader: &mut Reader) -> Result<String> {
+    let family = reader.u8()?;
+    let bits = reader.u8()?;
+    let is_cidr = reader.u8()? != 0;
+    let length = usize::from(reader.u8()?);
+    let address = reader.take(length)?;
+
+    let (address, max_bits) = match family {
+        2 => (
+            Ipv4Addr::from(<[u8; 4]>::try_from(address)?).to_string(),
+            32,
+        ),
+        3 => (
+            Ipv6Addr::from(<[u8; 16]>::try_from(address)?).to_string(),
+            128,
+        ),
+        other => bail!("unknown address family {}", other),
+    };
+    if is_cidr || bits != max_bits {
+        Ok(format!("{}/{}", address, bits))
+    } else {
+        Ok(address)
+    }
+}
+
+fn render_bits(reader: &mut Reader) -> Result<String> {
+    let length = reader.len()?;
+    let bytes = reader.rest();
+    Ok((0..length)
+        .map(|bit| {
+            let set = bytes
+                .get(bit / 8)
+                .is_some_and(|byte| byte & (0x80 >> (bit % 8)) != 0);
+            if set { '1' } else { '0' }
+        })
+        .collect())
+}
+
+fn render_points(reader: &mut Reader, count: usize) -> Result<String> {
+    let points = (0..count)
+        .map(|_| Ok(format!("({},{})", reader.f64()?, reader.f64()?)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(points.join(","))
+}
+
+fn render_snapshot(reader: &mut Reader) -> Result<String> {
+    let in_progress = reader.len()?;
+    let xmin = reader.u64()?;
+    let xmax = reader.u64()?;
+    let xids = (0..in_progress)
+        .map(|_| reader.u64().map(|xid| xid.to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(format!("{}:{}:{}", xmin, xmax, xids.join(",")))
+}
+
+fn render_tsvector(reader: &mut Reader) -> Result<String> {
+    let count = reader.len()?;
+    let mut lexemes = Vec::with_capacity(count);
+    for _ in 0..count {
+        let mut lexeme = quote_lexeme(reader.cstring()?);
+        let positions = usize::from(reader.u16()?);
+        let positions = (0..positions)
+            .map(|_| {
+                let entry = reader.u16()?;
+                let weight = match entry >> 14 {
+                    3 => "A",
+                    2 => "B",
+                    1 => "C",
+                    _ => "",
+                };
+                Ok(format!("{}{}", entry & 0x3FFF, weight))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if !positions.is_empty() {
+            lexeme.push(':');
+            lexeme.push_str(&positions.join(","));
+        }
+        lexemes.push(lexeme);
+    }
+    Ok(lexemes.join(" "))
+}
+
+/// Query items arrive in prefix order with the right operand first, as stored on disk.
+fn render_tsquery(reader: &mut Reader) -> Result<String> {
+    if reader.len()? == 0 {
+        return Ok(String::new());
+    }
+    render_tsquery_item(reader).map(|(rendered, _)| rendered)
+}
+
+fn render_tsquery_item(reader: &mut Reader) -> Result<(String, u8)> {
+    match reader.u8()? {
+        1 => {
+            let weight = reader.u8()?;
+            let prefix = reader.u8()? != 0;
+            let mut operand = quote_lexeme(reader.cstring()?);
+            if prefix || weight != 0 {
+                operand.push(':');
+                if prefix {
+                    operand.push('*');
+                }
+                for (bit, label) in [(8, 'A'), (4, 'B'), (2, 'C'), (1, 'D')] {
+                    if weight & bit != 0 {
+                        operand.push(label);
+                    }
+                }
+            }
+            Ok((operand, u8::MAX))
+        }
+        2 => {
+            let operator = reader.u8()?;
+            if operator == 1 {
+                let (operand, priority) = render_tsquery_item(reader)?;
+                return Ok((format!("!{}", parenthesize(operand, priority, 4)), 4));
+            }
+            let (symbol, priority) = match operator {
+                2 => ("&".to_string(), 2),
+                3 => ("|".to_string(), 1),
+                4 => match reader.i16()? {
+                    1 => ("<->".to_string(), 3),
+                    distance => (format!("<{}>", distance), 3),
+                },
+                other => bail!("unknown tsquery operator {}", other),
+            };
+            let (right, right_priority) = render_tsquery_item(reader)?;
+            let (left, left_priority) = render_tsquery_item(reader)?;
+            Ok((
+                format!(
+                    "{} {} {}",
+                    parenthesize(left, left_priority, priority),
+                    symbol,
+                    parenthesize(right, right_priority, priority)
+                ),
+                priority,
+            ))
+        }
+        other => bail!("unknown tsquery item type {}", other),
+    }
+}
+
+fn parenthesize(rendered: String, priority: u8, parent_priority: u8) -> String {
+    if priority < parent_priority {
+        format!("( {} )", rendered)
+    } else {
+        rendered
+    }
+}
+
+fn quote_lexeme(lexeme: &str) -> String {
+    format!("'{}'", lexeme.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+/// Renders a possibly multi-dimensional array, prefixed with its bounds when they do not
+/// start at 1, as in `[0:1]={a,b}`.
+fn render_array(bytes: &[u8], render: &dyn Fn(u32, &[u8]) -> Result<String>) -> Result<String> {
+    let mut reader = Reader::new(bytes);
+    let dimensions = reader.len()?;
+    let _has_nulls = reader.i32()?;
+    let element_oid = reader.u32()?;
+    if dimensions == 0 {
+        return Ok("{}".to_string());
+    }
+    let mut bounds = Vec::with_capacity(dimensions);
+    for _ in 0..dimensions {
+        bounds.push((reader.len()?, reader.i32()?));
+    }
+
+    let delimiter = if element_oid == oid::BOX { ';' } else { ',' };
+    let mut rendered = String::new();
+    if bounds.iter().any(|&(_, lower)| lower != 1) {
+        for &(length, lower) in &bounds {
+            write!(
+                rendered,
+                "[{}:{}]",
+                lower,
+                i64::from(lower) + length as i64 - 1
+            )?;
+        }
+        rendered.push('=');
+    }
+    render_array_dimension(
+        &mut reader,
+        &bounds,
+        delimiter,
+        &|bytes| render(element_oid, bytes),
+        &mut rendered,
+    )?;
+    Ok(rendered)
+}
+
+fn render_array_dimension(
+    reader: &mut Reader,
+    bounds: &[(usize, i32)],
+    delimiter: char,
+    render: &dyn Fn(&[u8]) -> Result<String>,
+    rendered: &mut String,
+) -> Result<()> {
+    rendered.push('{');
+    for idx in 0..bounds[0].0 {
+        if idx > 0 {
+            rendered.push(delimiter);
+        }
+        if bounds.len() > 1 {
+            render_array_dimension(reader, &bounds[1..], delimiter, render, rendered)?;
+        } else {
+            match reader.field()? {
+                Some(bytes) => rendered.push_str(&quote_array_element(&render(bytes)?, delimiter)),
+                None => rendered.push_str("NULL"),
+            }
+        }
+    }
+    rendered.push('}');
+    Ok(())
+}
+
+fn quote_array_element(element: &str, delimiter: char) -> String {
+    let needs_quotes = element.is_empty()
+        || element.eq_ignore_ascii_case("NULL")
+        || element
+            .chars()
+            .any(|c| matches!(c, '{' | '}' | '"' | '\\') || c == delimiter || c.is_whitespace());
+    if needs_quotes {
+        format!("\"{}\"", element.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        element.to_string()
+    }
+}
+
+fn quote_composite_field(field: &str, special: &str) -> String {
+    let needs_quotes = field.is_empty()
+        || field
+            .chars()
+            .any(|c| special.contains(c) || matches!(c, '"' | '\\') || c.is_whitespace());
+    if needs_quotes {
+        format!("\"{}\"", field.replace('\\', "\\\\").replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn render_range(bytes: &[u8], render: &dyn Fn(&[u8]) -> Result<String>) -> Result<String> {
+    const EMPTY: u8 = 0x01;
+    const LOWER_INCLUSIVE: u8 = 0x02;
+    const UPPER_INCLUSIVE: u8 = 0x04;
+    const LOWER_INFINITE: u8 = 0x08;
+    const UPPER_INFINITE: u8 = 0x10;
+
+    let mut reader = Reader::new(bytes);
+    let flags = reader.u8()?;
+    if flags & EMPTY != 0 {
+        return Ok("empty".to_string());
+    }
+    let mut bound = |infinite: u8| -> Result<String> {
+        if flags & infinite != 0 {
+            return Ok(String::new());
+        }
+        let length = reader.len()?;
+        let rendered = render(reader.take(length)?)?;
+        Ok(quote_composite_field(&rendered, "()[],"))
+    };
+    let lower = bound(LOWER_INFINITE)?;
+    let upper = bound(UPPER_INFINITE)?;
+    Ok(format!(
+        "{}{},{}{}",
+        if flags & LOWER_INCLUSIVE != 0 {
+            '['
+        } else {
+            '('
+        },
+        lower,
+        upper,
+        if flags & UPPER_INCLUSIVE != 0 {
+            ']'
+        } else {
+            ')'
+        }
+    ))
+}
+
+/// A cursor over the big-endian binary wire format.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < length {
+            bail!("value is shorter than its type requires");
+        }
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        Ok(self.take(N)?.try_into()?)
+    }
+
+    fn rest(&mut self) -> &'a [u8] {
+        std::mem::take(&mut self.bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        Ok(i16::from_be_bytes(self.array()?))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    fn i64(&mut self) -> Result<i64> {
+        Ok(i64::from_be_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_be_bytes(self.array()?))
+    }
+
+    fn f32(&mut self) -> Result<f32> {
+        Ok(f32::from_be_bytes(self.array()?))
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_be_bytes(self.array()?))
+    }
+
+    /// A non-negative 32-bit count or length.
+    fn len(&mut self) -> Result<usize> {
+        Ok(usize::try_from(self.i32()?)?)
+    }
+
+    /// A length-prefixed value, where a length of -1 stands for NULL.
+    fn field(&mut self) -> Result<Option<&'a [u8]>> {
+        match self.i32()? {
+            -1 => Ok(None),
+            length => Ok(Some(self.take(usize::try_from(length)?)?)),
+        }
+    }
+
+    fn cstring(&mut self) -> Result<&'a str> {
+        let end = self
+            .bytes
+            .iter()
+            .position(|&byte| byte == 0)
+            .ok_or_else(|| anyhow!("unterminated string"))?;
+        let text = std::str::from_utf8(&self.bytes[..end])?;
+        self.bytes = &self.bytes[end + 1..];
+        Ok(text)
+    }
+
+    fn version(&mut self) -> Result<()> {
+        match self.u8()? {
+            1 => Ok(()),
+            other => bail!("unsupported format version {}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renders a value from the hex of what the type's `*_send` function returns.
+    fn decode(type_oid: u32, hex: &str) -> String {
+        decode_with(&Decoder::new(), type_oid, hex)
+    }
+
+    fn decode_with(decoder: &Decoder, type_oid: u32, hex: &str) -> String {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).unwrap())
+            .collect();
+        decoder.decode_oid(type_oid, &bytes).unwrap().to_string()
+    }
+
+    #[test]
+    fn numeric_with_negative_weight_and_special_values() {
+        assert_eq!(decode(oid::NUMERIC, "0001ffff40000004000c"), "-0.0012");
+        assert_eq!(
+            decode(oid::NUMERIC, "0003000100000003000109291a7c"),
+            "12345.678"
+        );
+        assert_eq!(decode(oid::NUMERIC, "00000000c0000000"), "NaN");
+        assert_eq!(decode(oid::NUMERIC, "00000000d0000020"), "Infinity");
+        assert_eq!(decode(oid::NUMERIC, "00000000f0000020"), "-Infinity");
+    }
+
+    #[test]
+    fn interval_in_postgres_style() {
+        assert_eq!(
+            decode(oid::INTERVAL, "000000036c9361a0000000030000000e"),
+            "1 year 2 mons 3 days 04:05:06.5"
+        );
+        assert_eq!(
+            decode(oid::INTERVAL, "00000001ad274800ffffffff00000000"),
+            "-1 days +02:00:00"
+        );
+        assert_eq!(
+            decode(oid::INTERVAL, "00000000000000000000000000000000"),
+            "00:00:00"
+        );
+    }
+
+    #[test]
+    fn inet_shows_the_netmask_unless_it_covers_one_host() {
+        assert_eq!(decode(oid::INET, "02180004c0a80105"), "192.168.1.5/24");
+        assert_eq!(decode(oid::INET, "02200004c0a80105"), "192.168.1.5");
+        assert_eq!(
+            decode(oid::INET, "0380001000000000000000000000000000000001"),
+            "::1"
+        );
+    }
+
+    #[test]
+    fn cidr_always_shows_the_netmask() {
+        assert_eq!(decode(oid::CIDR, "020801040a000000"), "10.0.0.0/8");
+        assert_eq!(
+            decode(oid::CIDR, "0320011020010db8000000000000000000000000"),
+            "2001:db8::/32"
+        );
+    }
+
+    #[test]
+    fn arrays_not_starting_at_one_show_their_bounds() {
+        assert_eq!(
+            decode(
+                1009,
+                "0000000100000000000000190000000200000000000000016100000003622063"
+            ),
+            r#"[0:1]={a,"b c"}"#
+        );
+        assert_eq!(
+            decode(
+                1007,
+                "00000002000000010000001700000002000000000000000200000002000000040000000100000004\
+                 000000020000000400000003ffffffff"
+            ),
+            "[0:1][2:3]={{1,2},{3,NULL}}"
+        );
+    }
+
+    #[test]
+    fn ranges_with_inclusive_infinite_and_empty_bounds() {
+        assert_eq!(decode(3904, "0200000004000000010000000400000005"), "[1,5)");
+        assert_eq!(decode(3904, "080000000400000004"), "(,4)");
+        assert_eq!(decode(3904, "01"), "empty");
+        assert_eq!(decode(3906, "100000000c000200000000000100011388"), "(1.5,)");
+    }
+
+    #[test]
+    fn multiranges() {
+        assert_eq!(
+            decode(
+                4451,
+                "00000002000000110200000004000000010000000400000003000000110200000004000000050000\
+                 000400000008"
+            ),
+            "{[1,3),[5,8)}"
+        );
+        assert_eq!(decode(4451, "00000000"), "{}");
+    }
+
+    #[test]
+    fn infinite_dates_and_timestamps() {
+        assert_eq!(decode(oid::DATE, "7fffffff"), "infinity");
+        assert_eq!(decode(oid::DATE, "80000000"), "-infinity");
+        assert_eq!(decode(oid::TIMESTAMP, "7fffffffffffffff"), "infinity");
+        assert_eq!(decode(oid::TIMESTAMP, "8000000000000000"), "-infinity");
+        assert_eq!(decode(oid::TIMESTAMPTZ, "7fffffffffffffff"), "infinity");
+        assert_eq!(decode(oid::TIMESTAMPTZ, "8000000000000000"), "-infinity");
+    }
+
+    #[test]
+    fn money_follows_the_sessions_fraction_digits() {
+        let cents = "fffffffffffe1df3";
+        assert_eq!(decode(oid::MONEY, cents), "-1234.05");
+        let yen = Decoder::new().with_money_digits(0);
+        assert_eq!(decode_with(&yen, oid::MONEY, cents), "-123405");
+        let mils = Decoder::new().with_money_digits(3);
+        assert_eq!(decode_with(&mils, oid::MONEY, cents), "-123.405");
+    }
+}
@@ -1,10 +1,15 @@
-use crate::database::QueryResult;
+use crate::database::{QueryResult, Value, postgres::decoding::Decoder};
 use anyhow::Result;
-use sqlx::{postgres::PgRow, Column, Row, TypeInfo, ValueRef};
+use sqlx::{
+    Column, Row, TypeInfo, ValueRef,
+    postgres::{PgRow, PgValueFormat},
+};
 use std::time::Instant;
 
 #[derive(Clone)]
-pub struct PostgresFormatter;
+pub struct PostgresFormatter {
+    decoder: Decoder,
+}
 
 impl Default for PostgresFormatter {
     fn default() -> Self {
@@ -14,7 +19,14 @@ impl Default for PostgresFormatter {
 
 impl PostgresFormatter {
     pub fn new() -> Self {
-        Self
+        Self {
+            decoder: Decoder::new(),
+        }
+    }
+
+    pub fn with_money_digits(mut self, money_digits: u32) -> Self {
+        self.decoder = self.decoder.with_money_digits(money_digits);
+        self
     }
 
     pub fn rows_to_query_result(
@@ -31,6 +43,10 @@ impl PostgresFormatter {
                 row_count: 0,
                 execution_time: Some(execution_time),
                 column_types: vec![],
+                truncated: false,
+                column_sources: vec![],
+                outcome: None,
+                notices: Vec::new(),
             });
         }
 
@@ -49,12 +65,12 @@ impl PostgresFormatter {
 
         let mut result_rows = Vec::new();
         for row in &rows {
-            let mut string_row = Vec::new();
+            let mut value_row = Vec::new();
             for col_idx in 0..row.columns().len() {
-                let value = self.format_value(row, col_idx)?;
-                string_row.push(value);
+                let value = self.decode_value(row, col_idx)?;
+                value_row.push(value);
             }
-            result_rows.push(string_row);
+            result_rows.push(value_row);
         }
 
         Ok(QueryResult {
@@ -63,6 +79,10 @@ impl PostgresFormatter {
             row_count: rows.len(),
             execution_time: Some(execution_time),
             column_types,
+            truncated: false,
+            column_sources: vec![],
+            outcome: None,
+            notices: Vec::new(),
         })
     }
 
@@ -106,71 +126,23 @@ impl PostgresFormatter {
         }
     }
 
-    fn format_value(&self, row: &PgRow, col_idx: usize) -> Result<String> {
+    fn decode_value(&self, row: &PgRow, col_idx: usize) -> Result<Value> {
         let value_ref = row.try_get_raw(col_idx)?;
         if value_ref.is_null() {
-            return Ok("NULL".to_string());
+            return Ok(Value::Null);
         }
 
-        let type_info = value_ref.type_info();
-        let type_name = type_info.name();
-
-        match type_name {
-            "TEXT" | "VARCHAR" | "CHAR" | "BPCHAR" => {
-                Ok(row.try_get::<String, _>(col_idx).unwrap_or_default())
-            }
-            "INT2" | "INT4" | "INT8" | "SMALLINT" | "INTEGER" | "BIGINT" => {
-                if let Ok(val) = row.try_get::<i64, _>(col_idx) {
-                    Ok(val.to_string())
-                } else if let Ok(val) = row.try_get::<i32, _>(col_idx) {
-                    Ok(val.to_string())
-                } else if let Ok(val) = row.try_get::<i16, _>(col_idx) {
-                    Ok(val.to_string())
-                } else {
-                    Ok("0".to_string())
-                }
-            }
-            "FLOAT4" | "FLOAT8" | "REAL" | "DOUBLE PRECISION" | "NUMERIC" | "DECIMAL" => {
-                if let Ok(val) = row.try_get::<f64, _>(col_idx) {
-                    Ok(val.to_string())
-                } else if let Ok(val) = row.try_get::<f32, _>(col_idx) {
-                    Ok(val.to_string())
-                } else {
-                    Ok("0.0".to_string())
-                }
-            }
-            "BOOL" | "BOOLEAN" => {
-                if let Ok(val) = row.try_get::<bool, _>(col_idx) {
-                    Ok(val.to_string())
-                } else {
-                    Ok("false".to_string())
-                }
-            }
-            "TIMESTAMP" => Ok(row
-                .try_get::<chrono::NaiveDateTime, _>(col_idx)
-                .map_or("".to_string(), |v| {
-                    v.format("%Y-%m-%d %H:%M:%S").to_string()
-                })),
-            "TIMESTAMPTZ" => Ok(row
-                .try_get::<chrono::DateTime<chrono::Utc>, _>(col_idx)
-                .map_or("".to_string(), |v| {
-                    v.format("%Y-%m-%d %H:%M:%S %Z").to_string()
-                })),
-            "DATE" => Ok(row
-                .try_get::<chrono::NaiveDate, _>(col_idx)
-                .map_or("".to_string(), |v| v.format("%Y-%m-%d").to_string())),
-            "TIME" | "TIMETZ" => Ok(row.try_get::<String, _>(col_idx).unwrap_or_default()),
-            "UUID" => {
-                if let Ok(val) = row.try_get::<String, _>(col_idx) {
-                    Ok(val)
-                } else {
-                    Ok("".to_string())
-                }
-            }
-            "JSON" | "JSONB" => Ok(row.try_get::<String, _>(col_idx).unwrap_or_default()),
-            _ => row
-                .try_get::<String, _>(col_idx)
-                .or_else(|_| Ok(format!("<{}>", type_name))),
+        let type_info = value_ref.type_info().into_owned();
+        if value_ref.format() == PgValueFormat::Text {
+            return Ok(value_ref.as_str().map_or_else(
+                |_| Value::unsupported(type_info.name()),
+                |text| Value::Other(text.to_string()),
+            ));
         }
+        Ok(value_ref
+            .as_bytes()
+            .ok()
+            .and_then(|bytes| self.decoder.decode_value(&type_info, bytes).ok())
+            .unwrap_or_else(|| Value::unsupported(type_info.name())))
     }
 }
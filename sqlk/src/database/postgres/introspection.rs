@@ -0,0 +1,143 @@
+use crate::database::catalog::{
+    CatalogBuilder, ColumnInfo, ConstraintInfo, IndexInfo, SchemaInfo, TableKind,
+};
+use anyhow::Result;
+use sqlx::{PgPool, Row};
+
+const USER_SCHEMAS: &str = r#"
+    n.nspname NOT IN ('pg_catalog', 'information_schema')
+    AND n.nspname NOT LIKE 'pg\_toast%'
+    AND n.nspname NOT LIKE 'pg\_temp\_%'
+"#;
+
+/// Reads every user schema from the system catalogs.
+pub async fn introspect_schema(pool: &PgPool) -> Result<Vec<SchemaInfo>> {
+    let mut catalog = CatalogBuilder::new();
+
+    let schemas = sqlx::query(&format!(
+        "SELECT n.nspname::text FROM pg_namespace AS n WHERE {} ORDER BY 1",
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in schemas {
+        catalog.add_schema(row.get(0));
+    }
+
+    let tables = sqlx::query(&format!(
+        r#"
+        SELECT n.nspname::text, c.relname::text, c.relkind::text, c.reltuples::float8
+        FROM pg_class AS c
+            JOIN pg_namespace AS n ON n.oid = c.relnamespace
+        WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f') AND {}
+        ORDER BY 1, 2
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in tables {
+        let kind = match row.get::<String, _>(2).as_str() {
+            "v" => TableKind::View,
+            "m" => TableKind::MaterializedView,
+            "f" => TableKind::ForeignTable,
+            _ => TableKind::Table,
+        };
+        // reltuples is -1 for tables that have never been vacuumed or analyzed.
+        let estimated_rows = Some(row.get::<f64, _>(3))
+            .filter(|rows| *rows >= 0.0)
+            .map(|rows| rows as i64);
+        catalog.add_table(row.get(0), row.get(1), kind, estimated_rows);
+    }
+
+    let columns = sqlx::query(&format!(
+        r#"
+        SELECT
+            n.nspname::text,
+            c.relname::text,
+            a.attname::text,
+            format_type(a.atttypid, a.atttypmod),
+            NOT a.attnotnull,
+            pg_get_expr(d.adbin, d.adrelid)
+        FROM pg_attribute AS a
+            JOIN pg_class AS c ON c.oid = a.attrelid
+            JOIN pg_namespace AS n ON n.oid = c.relnamespace
+            LEFT JOIN pg_attrdef AS d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+        WHERE a.attnum > 0 AND NOT a.attisdropped
+            AND c.relkind IN ('r', 'p', 'v', 'm', 'f') AND {}
+        ORDER BY 1, 2, a.attnum
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in columns {
+        if let Some(table) = catalog.table_mut(row.get(0), row.get(1)) {
+            table.columns.push(ColumnInfo {
+                name: row.get(2),
+                data_type: row.get(3),
+                nullable: row.get(4),
+                default: row.get(5),
+            });
+        }
+    }
+
+    let indexes = sqlx::query(&format!(
+        r#"
+        SELECT
+            n.nspname::text,
+            t.relname::text,
+            i.relname::text,
+            ARRAY(
+                SELECT pg_get_indexdef(ix.indexrelid, k, true)
+                FROM generate_series(1, ix.indnkeyatts) AS k
+                ORDER BY k
+            ),
+            ix.indisunique,
+            ix.indisprimary
+        FROM pg_index AS ix
+            JOIN pg_class AS i ON i.oid = ix.indexrelid
+            JOIN pg_class AS t ON t.oid = ix.indrelid
+            JOIN pg_namespace AS n ON n.oid = t.relnamespace
+        WHERE {}
+        ORDER BY 1, 2, ix.indisprimary DESC, 3
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in indexes {
+        if let Some(table) = catalog.table_mut(row.get(0), row.get(1)) {
+            table.indexes.push(IndexInfo {
+                name: row.get(2),
+                columns: row.get(3),
+                unique: row.get(4),
+                primary: row.get(5),
+            });
+        }
+    }
+
+    let constraints = sqlx::query(&format!(
+        r#"
+        SELECT n.nspname::text, c.relname::text, con.conname::text, pg_get_constraintdef(con.oid)
+        FROM pg_constraint AS con
+            JOIN pg_class AS c ON c.oid = con.conrelid
+            JOIN pg_namespace AS n ON n.oid = c.relnamespace
+        WHERE {}
+        ORDER BY 1, 2, position(con.contype IN 'pufcx'), 3
+        "#,
+        USER_SCHEMAS
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in constraints {
+        if let Some(table) = catalog.table_mut(row.get(0), row.get(1)) {
+            table.constraints.push(ConstraintInfo {
+                name: row.get(2),
+                definition: row.get(3),
+            });
+        }
+    }
+
+    Ok(catalog.build())
+}
@@ -1,4 +1,7 @@
 pub mod client;
+pub mod decoding;
 pub mod formatting;
+pub mod introspection;
+pub mod notices;
 
 pub use client::PostgresClient;
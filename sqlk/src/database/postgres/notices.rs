@@ -0,0 +1,80 @@
+use crate::database::{NoticeSeverity, ServerNotice};
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::instrument::WithSubscriber;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+const NOTICE_TARGET: &str = "sqlx::postgres::notice";
+
+/// Runs `future`, returning its output with the notices the server sent while it ran.
+pub async fn collect<F: Future>(future: F) -> (F::Output, Vec<ServerNotice>) {
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let output = future
+        .with_subscriber(NoticeSubscriber {
+            notices: Arc::clone(&notices),
+        })
+        .await;
+    let notices = std::mem::take(&mut *notices.lock().unwrap());
+    (output, notices)
+}
+
+/// sqlx only reports notices as tracing events, so this listens for those and nothing else.
+/// It is the default subscriber only while the execution collecting them is polled.
+struct NoticeSubscriber {
+    notices: Arc<Mutex<Vec<ServerNotice>>>,
+}
+
+impl Subscriber for NoticeSubscriber {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == NOTICE_TARGET
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        // The driver maps server severities onto tracing levels; this undoes that mapping.
+        let severity = match *event.metadata().level() {
+            Level::ERROR | Level::WARN => NoticeSeverity::Warning,
+            Level::INFO => NoticeSeverity::Notice,
+            Level::DEBUG => NoticeSeverity::Debug,
+            Level::TRACE => NoticeSeverity::Info,
+        };
+        let mut visitor = MessageVisitor(None);
+        event.record(&mut visitor);
+        if let Some(message) = visitor.0 {
+            self.notices
+                .lock()
+                .unwrap()
+                .push(ServerNotice { severity, message });
+        }
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+struct MessageVisitor(Option<String>);
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = Some(format!("{:?}", value));
+        }
+    }
+}
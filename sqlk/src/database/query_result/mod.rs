@@ -1,4 +1,4 @@
-use crate::database::QueryResult;
+use crate::database::{ExecutionOutcome, QueryResult};
 
 impl QueryResult {
     pub fn is_empty(&self) -> bool {
@@ -8,4 +8,28 @@ impl QueryResult {
     pub fn get_column_index(&self, column_name: &str) -> Option<usize> {
         self.headers.iter().position(|h| h == column_name)
     }
+
+    /// The outcome with its timing, e.g. `UPDATE 42 (12 ms)`.
+    pub fn outcome_summary(&self) -> Option<String> {
+        let outcome = self.outcome.as_ref()?;
+        Some(match self.execution_time {
+            Some(duration) => format!("{} ({} ms)", outcome.summary(), duration.as_millis()),
+            None => outcome.summary(),
+        })
+    }
+}
+
+impl ExecutionOutcome {
+    /// Reads like the server's command tag; only DML carries a row count.
+    pub fn summary(&self) -> String {
+        let counts_rows = matches!(
+            self.command_tag.as_str(),
+            "INSERT" | "UPDATE" | "DELETE" | "MERGE" | "REPLACE" | "COPY"
+        );
+        if counts_rows {
+            format!("{} {}", self.command_tag, self.rows_affected)
+        } else {
+            self.command_tag.clone()
+        }
+    }
 }
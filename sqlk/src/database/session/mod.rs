@@ -0,0 +1,194 @@
+use anyhow::{Result, anyhow};
+use futures::TryFutureExt;
+use futures::future::BoxFuture;
+use sqlx::pool::PoolConnection;
+use sqlx::{Connection, Database, Executor, MySqlConnection, PgConnection, Pool, SqliteConnection};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::query_parser::analyzer::TransactionControl;
+
+/// A connection pinned from the pool between `BEGIN` and `COMMIT`/`ROLLBACK`, so statements
+/// run one at a time still share the open transaction.
+pub struct TransactionSession<DB: Database> {
+    connection: Arc<Mutex<Option<PoolConnection<DB>>>>,
+    active: Arc<AtomicBool>,
+    /// Undoes a statement that was interrupted on the pinned connection, before the next one.
+    owed_undo: Arc<std::sync::Mutex<Option<&'static str>>>,
+}
+
+impl<DB: Database> TransactionSession<DB> {
+    pub fn new() -> Self {
+        Self {
+            connection: Arc::new(Mutex::new(None)),
+            active: Arc::new(AtomicBool::new(false)),
+            owed_undo: Arc::new(std::sync::Mutex::new(None)),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    /// Hands out the pinned connection if a transaction is open, otherwise one from the pool.
+    /// The session stays locked only while its pinned connection is in use, or while a
+    /// `BEGIN` runs that may pin one, so other statements still run concurrently.
+    pub async fn checkout(
+        &self,
+        pool: &Pool<DB>,
+        control: Option<TransactionControl>,
+    ) -> Result<SessionConnection<'_, DB>>
+    where
+        DB::Connection: ExecuteRaw,
+    {
+        let guard = self.connection.lock().await;
+        let mut pinned =
+            (guard.is_some() || control == Some(TransactionControl::Begin)).then_some(guard);
+        let owed_undo = *self.owed_undo.lock().unwrap();
+        if let Some(undo) = owed_undo
+            && let Some(connection) = pinned.as_deref_mut().and_then(Option::as_mut)
+        {
+            // The interrupted statement's reply, often a cancellation error, is read first so
+            // it is not taken for the undo's. It stays owed until it has run, so an
+            // interruption here does not lose it.
+            let _ = connection.ping().await;
+            let undone = connection.execute_raw(undo).await;
+            *self.owed_undo.lock().unwrap() = None;
+            undone.map_err(|e| {
+                anyhow!(
+                    "Undoing an interrupted statement in the transaction failed: {}",
+                    e
+                )
+            })?;
+        }
+        let pooled = if pinned.as_ref().is_some_and(|pinned| pinned.is_some()) {
+            None
+        } else {
+            Some(pool.acquire().await?)
+        };
+        Ok(SessionConnection {
+            pinned,
+            pooled,
+            control,
+            active: &self.active,
+            owed_undo: &self.owed_undo,
+            undo_on_drop: None,
+        })
+    }
+}
+
+impl<DB: Database> Default for TransactionSession<DB> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<DB: Database> Clone for TransactionSession<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            connection: self.connection.clone(),
+            active: self.active.clone(),
+            owed_undo: self.owed_undo.clone(),
+        }
+    }
+}
+
+/// The connection one statement runs on. When it holds the session lock, that is kept until
+/// the statement is finished.
+pub struct SessionConnection<'a, DB: Database> {
+    pinned: Option<MutexGuard<'a, Option<PoolConnection<DB>>>>,
+    pooled: Option<PoolConnection<DB>>,
+    control: Option<TransactionControl>,
+    active: &'a AtomicBool,
+    owed_undo: &'a std::sync::Mutex<Option<&'static str>>,
+    undo_on_drop: Option<&'static str>,
+}
+
+impl<DB: Database> SessionConnection<'_, DB> {
+    pub fn connection(&mut self) -> &mut PoolConnection<DB> {
+        self.pinned
+            .as_deref_mut()
+            .and_then(Option::as_mut)
+            .or(self.pooled.as_mut())
+            .expect("checkout always provides a connection")
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.as_ref().is_some_and(|pinned| pinned.is_some())
+    }
+
+    /// Hands over a connection from the pool for a result that is read after the statement
+    /// has returned. The pinned connection stays with the session, so it gives `None`.
+    pub fn take_pooled(mut self) -> Option<PoolConnection<DB>> {
+        if self.is_pinned() {
+            return None;
+        }
+        self.pooled.take()
+    }
+
+    /// Set around statements that must run as a pair, such as `SAVEPOINT` and `ROLLBACK TO
+    /// SAVEPOINT`, in case the task is aborted between them. A pooled connection dropped while
+    /// it is set is closed, which ends any transaction begun on it. The pinned connection
+    /// keeps the user's transaction open and runs `undo` before its next statement instead.
+    pub fn set_undo_on_drop(&mut self, undo: Option<&'static str>) {
+        self.undo_on_drop = undo;
+    }
+
+    /// Pins the connection after a successful `BEGIN` and releases it after `COMMIT` or
+    /// `ROLLBACK`, which end the transaction even when they fail.
+    pub fn finish(mut self, succeeded: bool) {
+        let Some(pinned) = self.pinned.as_deref_mut() else {
+            return;
+        };
+        match self.control {
+            Some(TransactionControl::Begin) if succeeded && pinned.is_none() => {
+                *pinned = self.pooled.take();
+                self.active.store(true, Ordering::SeqCst);
+            }
+            Some(TransactionControl::End) if pinned.is_some() => {
+                *pinned = None;
+                self.active.store(false, Ordering::SeqCst);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<DB: Database> Drop for SessionConnection<'_, DB> {
+    fn drop(&mut self) {
+        let Some(undo) = self.undo_on_drop else {
+            return;
+        };
+        if self.is_pinned() {
+            *self.owed_undo.lock().unwrap() = Some(undo);
+        }
+        if let Some(connection) = self.pooled.as_mut() {
+            connection.close_on_drop();
+        }
+    }
+}
+
+/// Runs SQL without parameters on a backend's connection, for statements the session sends
+/// on its own.
+pub trait ExecuteRaw {
+    fn execute_raw<'c>(&'c mut self, sql: &'static str) -> BoxFuture<'c, sqlx::Result<()>>;
+}
+
+impl ExecuteRaw for PgConnection {
+    fn execute_raw<'c>(&'c mut self, sql: &'static str) -> BoxFuture<'c, sqlx::Result<()>> {
+        Box::pin(self.execute(sql).map_ok(|_| ()))
+    }
+}
+
+impl ExecuteRaw for MySqlConnection {
+    fn execute_raw<'c>(&'c mut self, sql: &'static str) -> BoxFuture<'c, sqlx::Result<()>> {
+        Box::pin(self.execute(sql).map_ok(|_| ()))
+    }
+}
+
+impl ExecuteRaw for SqliteConnection {
+    fn execute_raw<'c>(&'c mut self, sql: &'static str) -> BoxFuture<'c, sqlx::Result<()>> {
+        Box::pin(self.execute(sql).map_ok(|_| ()))
+    }
+}
@@ -0,0 +1,417 @@
+use super::formatting::SqliteFormatter;
+use super::introspection;
+use crate::config::Config;
+use crate::database::fk::infer_column_sources;
+use crate::database::pager::{OpenResult, ResultSlot, read_page};
+use crate::database::param::params_for;
+use crate::database::pool;
+use crate::database::{
+    CatalogColumn, ColumnRef, DatabaseClient, ExecutionOutcome, ForeignKeyInfo, QueryParam,
+    QueryResult, SchemaCache, SchemaInfo, TransactionSession, Value,
+};
+use crate::query_parser::QueryAnalyzer;
+use crate::query_parser::placeholder::{
+    find_placeholders, placeholder_names, replace_placeholders,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use sqlx::Row;
+use sqlx::query::Query;
+use sqlx::sqlite::{SqliteArguments, SqliteConnectOptions, SqliteConnection, SqliteRow};
+use sqlx::{Either, Executor, Sqlite, SqlitePool};
+use std::str::FromStr;
+use std::time::Instant;
+
+#[derive(Clone)]
+pub struct SqliteClient {
+    pool: SqlitePool,
+    schema_cache: SchemaCache,
+    formatter: SqliteFormatter,
+    session: TransactionSession<Sqlite>,
+    open_result: ResultSlot<SqliteRow>,
+}
+
+impl SqliteClient {
+    pub async fn new(config: &Config) -> Result<Self> {
+        let database_url = config
+            .get_database_url()
+            .ok_or_else(|| anyhow::anyhow!("No DATABASE_URL found"))?;
+
+        let options = SqliteConnectOptions::from_str(database_url)?;
+        let setup = pool::SessionSetup::new(&config.database, "PRAGMA query_only = ON");
+        let pool_options = pool::pool_options::<Sqlite>(&config.database.pool).after_connect({
+            let setup = setup.clone();
+            move |connection, _| {
+                let setup = setup.clone();
+                Box::pin(async move { setup.run(connection).await })
+            }
+        });
+        let pool = setup.connect(pool_options, options).await?;
+        let formatter = SqliteFormatter::new();
+
+        let mut client = Self {
+            pool,
+            schema_cache: SchemaCache::new(),
+            formatter,
+            session: TransactionSession::new(),
+            open_result: ResultSlot::new(),
+        };
+
+        if config.foreign_keys.enabled {
+            client.schema_cache = client.analyze_schema(&config.foreign_keys.schemas).await?;
+            if !config.foreign_keys.manual_mapping.is_empty() {
+                let columns = client.catalog_columns(&config.foreign_keys.schemas).await?;
+                client
+                    .schema_cache
+                    .apply_manual_mapping(&config.foreign_keys.manual_mapping, &columns);
+            }
+        }
+
+        Ok(client)
+    }
+
+    async fn analyze_schema(&self, schemas: &[String]) -> Result<SchemaCache> {
+        let mut schema_cache = SchemaCache::new();
+        for schema in &scanned_schemas(schemas) {
+            let tables: Vec<String> = sqlx::query(&format!(
+                "SELECT name FROM {}.sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+                quote_identifier(schema)
+            ))
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(|row| row.get("name"))
+            .collect();
+
+            for table in tables {
+                let query = format!(
+                    "PRAGMA {}.foreign_key_list({})",
+                    quote_identifier(schema),
+                    quote_identifier(&table)
+                );
+                let rows = sqlx::query(&query).fetch_all(&self.pool).await?;
+
+                // One row per key component, grouped by constraint `id` and ordered by `seq`.
+                let mut foreign_keys: Vec<(i64, ForeignKeyInfo)> = Vec::new();
+                for row in rows {
+                    let id: i64 = row.get("id");
+                    let column: String = row.get("from");
+                    let referenced_column: Option<String> = row.get("to");
+                    let referenced_column = referenced_column.unwrap_or_default();
+
+                    match foreign_keys.iter_mut().find(|(fk_id, _)| *fk_id == id) {
+                        Some((_, fk_info)) => {
+                            fk_info.columns.push(column);
+                            fk_info.referenced_columns.push(referenced_column);
+                        }
+                        None => foreign_keys.push((
+                            id,
+                            ForeignKeyInfo {
+                                constraint_name: format!("{}_fk_{}", table, id),
+                                schema: schema.clone(),
+                                table_name: table.clone(),
+                                columns: vec![column],
+                                referenced_schema: schema.clone(),
+                                referenced_table: row.get("table"),
+                                referenced_columns: vec![referenced_column],
+                            },
+                        )),
+                    }
+                }
+
+                for (_, mut fk_info) in foreign_keys {
+                    // `to` is NULL when the constraint targets the parent's primary key.
+                    if fk_info.referenced_columns.iter().any(String::is_empty) {
+                        let primary_key = self
+                            .primary_key_columns(schema, &fk_info.referenced_table)
+                            .await?;
+                        if primary_key.len() != fk_info.columns.len() {
+                            continue;
+                        }
+                        fk_info.referenced_columns = primary_key;
+                    }
+                    schema_cache.insert(fk_info);
+                }
+            }
+        }
+
+        Ok(schema_cache)
+    }
+
+    /// Every column in the scanned schemas, for resolving manual foreign key mappings.
+    async fn catalog_columns(&self, schemas: &[String]) -> Result<Vec<CatalogColumn>> {
+        let mut columns = Vec::new();
+        for schema in &scanned_schemas(schemas) {
+            let tables: Vec<String> = sqlx::query(&format!(
+                "SELECT name FROM {}.sqlite_master WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%'",
+                quote_identifier(schema)
+            ))
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(|row| row.get("name"))
+            .collect();
+
+            for table in tables {
+                let query = format!(
+                    "PRAGMA {}.table_info({})",
+                    quote_identifier(schema),
+                    quote_identifier(&table)
+                );
+                for row in sqlx::query(&query).fetch_all(&self.pool).await? {
+                    columns.push((schema.clone(), table.clone(), row.get("name")));
+                }
+            }
+        }
+        Ok(columns)
+    }
+
+    async fn primary_key_columns(&self, schema: &str, table: &str) -> Result<Vec<String>> {
+        let query = format!(
+            "PRAGMA {}.table_info({})",
+            quote_identifier(schema),
+            quote_identifier(table)
+        );
+        let rows = sqlx::query(&query).fetch_all(&self.pool).await?;
+
+        let mut columns: Vec<(i64, String)> = rows
+            .iter()
+            .map(|row| (row.get::<i64, _>("pk"), row.get("name")))
+            .filter(|(pk, _)| *pk > 0)
+            .collect();
+        columns.sort_by_key(|(pk, _)| *pk);
+        Ok(columns.into_iter().map(|(_, name)| name).collect())
+    }
+}
+
+/// The configured schemas, or `main` when none are set.
+fn scanned_schemas(schemas: &[String]) -> Vec<String> {
+    if schemas.is_empty() {
+        vec!["main".to_string()]
+    } else {
+        schemas.to_vec()
+    }
+}
+
+pub(super) fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// `schema.table WHERE c1 = .. AND c2 = ..`, with one placeholder per key column.
+fn key_filter(schema: &str, table: &str, columns: &[String]) -> String {
+    let conditions = columns
+        .iter()
+        .map(|column| format!("{} = ?", quote_identifier(column)))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    format!(
+        "{}.{} WHERE {}",
+        quote_identifier(schema),
+        quote_identifier(table),
+        conditions
+    )
+}
+
+fn bind_value<'q>(
+    query: Query<'q, Sqlite, SqliteArguments<'q>>,
+    value: &Value,
+) -> Query<'q, Sqlite, SqliteArguments<'q>> {
+    match value {
+        Value::Null => query.bind(None::<String>),
+        Value::Bool(val) => query.bind(*val),
+        Value::Int(val) => query.bind(*val),
+        Value::Float(val) => query.bind(*val),
+        Value::Decimal(val) | Value::Text(val) | Value::Other(val) => query.bind(val.clone()),
+        Value::Bytes(val) => query.bind(val.clone()),
+        Value::Json(val) => query.bind(sqlx::types::Json(val.clone())),
+        Value::Date(val) => query.bind(*val),
+        Value::Time(val) => query.bind(*val),
+        Value::Timestamp(val) => query.bind(*val),
+        Value::TimestampTz(val) => query.bind(*val),
+        Value::Uuid(val) => query.bind(val.to_string()),
+    }
+}
+
+fn fetch_rows<'c>(
+    conn: &'c mut SqliteConnection,
+    statement: &'c str,
+    values: Vec<Value>,
+) -> BoxStream<'c, sqlx::Result<Either<u64, SqliteRow>>> {
+    let mut bound = sqlx::query(statement);
+    for value in &values {
+        bound = bind_value(bound, value);
+    }
+    conn.fetch_many(bound)
+        .map_ok(|item| item.map_left(|done| done.rows_affected()))
+        .boxed()
+}
+
+/// Rewrites placeholders as `?n`, which sqlx can bind, and returns the values in order.
+fn numbered_placeholders(query: &str, params: &[QueryParam]) -> Result<(String, Vec<Value>)> {
+    if params.is_empty() {
+        return Ok((query.to_string(), Vec::new()));
+    }
+    let placeholders = find_placeholders(query);
+    let values = params_for(&placeholder_names(&placeholders), params)?
+        .into_iter()
+        .map(QueryParam::value)
+        .collect();
+    let statement =
+        replace_placeholders(query, &placeholders, |position, _| format!("?{}", position));
+    Ok((statement, values))
+}
+
+#[async_trait]
+impl DatabaseClient for SqliteClient {
+    async fn execute_query(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        row_limit: usize,
+    ) -> Result<QueryResult> {
+        let start_time = Instant::now();
+        // The viewer is about to show a new result, so the last one's rows are not needed.
+        self.open_result.close();
+        let analyzer = QueryAnalyzer::new();
+        let command_tag = analyzer.command_tag(query);
+        let control = analyzer.transaction_control(query);
+        let (statement, values) = numbered_placeholders(query, params)?;
+        let mut session = self.session.checkout(&self.pool, control).await?;
+        // Statements with side effects run to completion so the count is right.
+        let drain = command_tag.is_some();
+
+        let fetched = if drain || control.is_some() || session.is_pinned() {
+            let conn = session.connection();
+            let fetched = async {
+                let mut stream = fetch_rows(conn, &statement, values);
+                let page = read_page(&mut stream, &mut None, row_limit, drain).await?;
+                drop(stream);
+                anyhow::Ok(page)
+            }
+            .await;
+            session.finish(fetched.is_ok());
+            fetched?
+        } else {
+            let mut conn = session
+                .take_pooled()
+                .expect("an unpinned checkout holds a pooled connection");
+            // The rest of the result stays open on a task of its own, so more rows can be
+            // read later without running the query again.
+            let (open, requests) = OpenResult::new();
+            tokio::spawn(async move {
+                let mut stream = fetch_rows(&mut conn, &statement, values);
+                requests.serve(&mut stream).await;
+            });
+            let page = open.next_page(row_limit).await?;
+            if page.more {
+                self.open_result.keep(open);
+            }
+            page
+        };
+
+        let mut result = self
+            .formatter
+            .rows_to_query_result(fetched.rows, start_time)?;
+        result.truncated = fetched.more;
+        result.outcome = command_tag.map(|command_tag| ExecutionOutcome {
+            command_tag,
+            rows_affected: fetched.rows_affected,
+        });
+        result.column_sources = infer_column_sources(&self.schema_cache, query, &result.headers);
+        Ok(result)
+    }
+
+    async fn fetch_more(&self, row_limit: usize) -> Result<QueryResult> {
+        let start_time = Instant::now();
+        let page = self.open_result.next_page(row_limit).await?;
+        let mut result = self.formatter.rows_to_query_result(page.rows, start_time)?;
+        result.truncated = page.more;
+        Ok(result)
+    }
+
+    async fn fetch_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+        limit: usize,
+    ) -> Result<QueryResult> {
+        let query_string = format!(
+            "SELECT * FROM {} LIMIT {}",
+            key_filter(schema, table, columns),
+            limit + 1
+        );
+
+        let mut session = self.session.checkout(&self.pool, None).await?;
+        let start_time = Instant::now();
+        let mut rows = values
+            .iter()
+            .fold(sqlx::query(&query_string), bind_value)
+            .fetch_all(&mut **session.connection())
+            .await?;
+        let truncated = rows.len() > limit;
+        rows.truncate(limit);
+
+        let mut result = self.formatter.rows_to_query_result(rows, start_time)?;
+        result.truncated = truncated;
+        result.column_sources = ColumnRef::for_table(schema, table, &result.headers);
+        Ok(result)
+    }
+
+    async fn count_rows_by_key(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+        values: &[Value],
+    ) -> Result<i64> {
+        let query_string = format!(
+            "SELECT COUNT(*) FROM {}",
+            key_filter(schema, table, columns)
+        );
+        let mut session = self.session.checkout(&self.pool, None).await?;
+        let count = values
+            .iter()
+            .fold(sqlx::query(&query_string), bind_value)
+            .fetch_one(&mut **session.connection())
+            .await?
+            .try_get(0)?;
+        Ok(count)
+    }
+
+    fn get_foreign_key_info(&self, column: &ColumnRef) -> Option<&ForeignKeyInfo> {
+        self.schema_cache.get(column)
+    }
+
+    fn get_referencing_foreign_keys(
+        &self,
+        schema: Option<&str>,
+        table: &str,
+    ) -> Vec<&ForeignKeyInfo> {
+        self.schema_cache.referencing(schema, table)
+    }
+
+    fn foreign_key_warnings(&self) -> &[String] {
+        self.schema_cache.warnings()
+    }
+
+    async fn introspect_schema(&self) -> Result<Vec<SchemaInfo>> {
+        introspection::introspect_schema(&self.pool).await
+    }
+
+    fn preview_query(&self, schema: &str, table: &str, limit: usize) -> String {
+        format!(
+            "SELECT * FROM {}.{} LIMIT {}",
+            quote_identifier(schema),
+            quote_identifier(table),
+            limit
+        )
+    }
+
+    fn in_transaction(&self) -> bool {
+        self.session.is_active()
+    }
+}
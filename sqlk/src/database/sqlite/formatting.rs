@@ -0,0 +1,146 @@
+use crate::database::{QueryResult, Value};
+use anyhow::Result;
+use sqlx::{Column, Row, TypeInfo, ValueRef, sqlite::SqliteRow};
+use std::time::Instant;
+
+#[derive(Clone)]
+pub struct SqliteFormatter;
+
+impl Default for SqliteFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SqliteFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn rows_to_query_result(
+        &self,
+        rows: Vec<SqliteRow>,
+        start_time: Instant,
+    ) -> Result<QueryResult> {
+        let execution_time = start_time.elapsed();
+
+        if rows.is_empty() {
+            return Ok(QueryResult {
+                headers: vec![],
+                rows: vec![],
+                row_count: 0,
+                execution_time: Some(execution_time),
+                column_types: vec![],
+                truncated: false,
+                column_sources: vec![],
+                outcome: None,
+                notices: Vec::new(),
+            });
+        }
+
+        let headers: Vec<String> = rows[0]
+            .columns()
+            .iter()
+            .map(|col| col.name().to_string())
+            .collect();
+
+        let column_types: Vec<String> = rows[0]
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(idx, _col)| self.get_column_type_from_rows(&rows, idx))
+            .collect();
+
+        let mut result_rows = Vec::new();
+        for row in &rows {
+            let mut value_row = Vec::new();
+            for col_idx in 0..row.columns().len() {
+                let value = self.decode_value(row, col_idx)?;
+                value_row.push(value);
+            }
+            result_rows.push(value_row);
+        }
+
+        Ok(QueryResult {
+            headers,
+            rows: result_rows,
+            row_count: rows.len(),
+            execution_time: Some(execution_time),
+            column_types,
+            truncated: false,
+            column_sources: vec![],
+            outcome: None,
+            notices: Vec::new(),
+        })
+    }
+
+    /// SQLite is dynamically typed, so the declared column type is preferred and the
+    /// storage class of the first non-null value is used for expressions.
+    fn get_column_type_from_rows(&self, rows: &[SqliteRow], col_idx: usize) -> String {
+        if let Some(first_row) = rows.first()
+            && let Some(column) = first_row.columns().get(col_idx)
+            && !column.type_info().is_null()
+        {
+            return self.normalize_type_name(column.type_info().name());
+        }
+
+        for row in rows {
+            if let Ok(value_ref) = row.try_get_raw(col_idx)
+                && !value_ref.is_null()
+            {
+                let type_info = value_ref.type_info();
+                return self.normalize_type_name(type_info.name());
+            }
+        }
+
+        "UNKNOWN".to_string()
+    }
+
+    fn normalize_type_name(&self, type_name: &str) -> String {
+        match type_name {
+            "TEXT" => "TEXT".to_string(),
+            "INTEGER" => "INTEGER".to_string(),
+            "REAL" => "REAL".to_string(),
+            "NUMERIC" => "NUMERIC".to_string(),
+            "BOOLEAN" => "BOOLEAN".to_string(),
+            "BLOB" => "BLOB".to_string(),
+            "DATE" => "DATE".to_string(),
+            "TIME" => "TIME".to_string(),
+            "DATETIME" => "TIMESTAMP".to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    fn decode_value(&self, row: &SqliteRow, col_idx: usize) -> Result<Value> {
+        let value_ref = row.try_get_raw(col_idx)?;
+        if value_ref.is_null() {
+            return Ok(Value::Null);
+        }
+
+        let declared_type = row.columns()[col_idx].type_info().name();
+        let type_info = value_ref.type_info();
+        let type_name = if declared_type == "BOOLEAN" {
+            declared_type
+        } else {
+            type_info.name()
+        };
+
+        match type_name {
+            "INTEGER" => Ok(row
+                .try_get_unchecked::<i64, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Int)),
+            "REAL" | "NUMERIC" => Ok(row
+                .try_get_unchecked::<f64, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Float)),
+            "BOOLEAN" => Ok(row
+                .try_get_unchecked::<bool, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Bool)),
+            "BLOB" => Ok(row
+                .try_get_unchecked::<Vec<u8>, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Bytes)),
+            _ => Ok(row
+                .try_get_unchecked::<String, _>(col_idx)
+                .map_or_else(|_| Value::unsupported(type_name), Value::Text)),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use super::client::quote_identifier;
+use crate::database::catalog::{
+    CatalogBuilder, ColumnInfo, ConstraintInfo, IndexInfo, SchemaInfo, TableKind,
+};
+use anyhow::Result;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+
+/// Reads `main` and any attached databases through the schema pragmas.
+pub async fn introspect_schema(pool: &SqlitePool) -> Result<Vec<SchemaInfo>> {
+    let mut catalog = CatalogBuilder::new();
+
+    let schemas: Vec<String> = sqlx::query("PRAGMA database_list")
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|row| row.get("name"))
+        .filter(|name: &String| name != "temp")
+        .collect();
+
+    for schema in &schemas {
+        catalog.add_schema(schema);
+        let estimates = row_estimates(pool, schema).await;
+
+        let tables = sqlx::query(&format!(
+            "SELECT name, type FROM {}.sqlite_master \
+             WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%' ORDER BY name",
+            quote_identifier(schema)
+        ))
+        .fetch_all(pool)
+        .await?;
+
+        for row in tables {
+            let name: String = row.get("name");
+            let kind = match row.get::<String, _>("type").as_str() {
+                "view" => TableKind::View,
+                _ => TableKind::Table,
+            };
+            catalog.add_table(schema, &name, kind, estimates.get(&name).copied());
+            let Some(table) = catalog.table_mut(schema, &name) else {
+                continue;
+            };
+
+            let columns = sqlx::query(&pragma(schema, "table_info", &name))
+                .fetch_all(pool)
+                .await?;
+            let mut primary_key: Vec<(i64, String)> = Vec::new();
+            for column in columns {
+                let column_name: String = column.get("name");
+                let pk: i64 = column.get("pk");
+                if pk > 0 {
+                    primary_key.push((pk, column_name.clone()));
+                }
+                table.columns.push(ColumnInfo {
+                    name: column_name,
+                    data_type: column.get("type"),
+                    nullable: column.get::<i64, _>("notnull") == 0 && pk == 0,
+                    default: column.get("dflt_value"),
+                });
+            }
+            primary_key.sort_by_key(|(pk, _)| *pk);
+            let primary_key: Vec<String> = primary_key.into_iter().map(|(_, name)| name).collect();
+            if !primary_key.is_empty() {
+                table.constraints.push(ConstraintInfo {
+                    name: format!("{}_pkey", name),
+                    definition: format!("PRIMARY KEY ({})", primary_key.join(", ")),
+                });
+            }
+
+            if kind == TableKind::View {
+                continue;
+            }
+
+            let index_list = sqlx::query(&pragma(schema, "index_list", &name))
+                .fetch_all(pool)
+                .await?;
+            for index in index_list {
+                let index_name: String = index.get("name");
+                let origin: String = index.get("origin");
+                let index_columns: Vec<String> =
+                    sqlx::query(&pragma(schema, "index_info", &index_name))
+                        .fetch_all(pool)
+                        .await?
+                        .iter()
+                        .map(|row| {
+                            row.get::<Option<String>, _>("name")
+                                .unwrap_or_else(|| "(expression)".to_string())
+                        })
+                        .collect();
+                if origin == "u" {
+                    table.constraints.push(ConstraintInfo {
+                        name: index_name.clone(),
+                        definition: format!("UNIQUE ({})", index_columns.join(", ")),
+                    });
+                }
+                table.indexes.push(IndexInfo {
+                    name: index_name,
+                    columns: index_columns,
+                    unique: index.get::<i64, _>("unique") != 0,
+                    primary: origin == "pk",
+                });
+            }
+            table.indexes.sort_by_key(|index| !index.primary);
+
+            // One row per key column, grouped by constraint `id`.
+            let foreign_keys = sqlx::query(&pragma(schema, "foreign_key_list", &name))
+                .fetch_all(pool)
+                .await?;
+            let mut grouped: Vec<(i64, String, Vec<String>, Vec<String>)> = Vec::new();
+            for row in foreign_keys {
+                let id: i64 = row.get("id");
+                let from: String = row.get("from");
+                let to: Option<String> = row.get("to");
+                match grouped.iter_mut().find(|(fk_id, ..)| *fk_id == id) {
+                    Some((_, _, from_columns, to_columns)) => {
+                        from_columns.push(from);
+                        to_columns.extend(to);
+                    }
+                    None => {
+                        grouped.push((id, row.get("table"), vec![from], to.into_iter().collect()))
+                    }
+                }
+            }
+            for (id, referenced_table, from_columns, to_columns) in grouped {
+                // `to` is NULL when the constraint targets the parent's primary key.
+                let referenced = if to_columns.is_empty() {
+                    referenced_table
+                } else {
+                    format!("{}({})", referenced_table, to_columns.join(", "))
+                };
+                table.constraints.push(ConstraintInfo {
+                    name: format!("{}_fk_{}", name, id),
+                    definition: format!(
+                        "FOREIGN KEY ({}) REFERENCES {}",
+                        from_columns.join(", "),
+                        referenced
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(catalog.build())
+}
+
+/// Row counts recorded by `ANALYZE`, when the database has been analyzed.
+async fn row_estimates(pool: &SqlitePool, schema: &str) -> HashMap<String, i64> {
+    let query = format!(
+        "SELECT tbl, stat FROM {}.sqlite_stat1",
+        quote_identifier(schema)
+    );
+    let Ok(rows) = sqlx::query(&query).fetch_all(pool).await else {
+        return HashMap::new();
+    };
+    rows.iter()
+        .filter_map(|row| {
+            let stat: String = row.get("stat");
+            let rows = stat.split_whitespace().next()?.parse().ok()?;
+            Some((row.get("tbl"), rows))
+        })
+        .collect()
+}
+
+fn pragma(schema: &str, name: &str, argument: &str) -> String {
+    format!(
+        "PRAGMA {}.{}({})",
+        quote_identifier(schema),
+        name,
+        quote_identifier(argument)
+    )
+}
@@ -0,0 +1,5 @@
+pub mod client;
+pub mod formatting;
+pub mod introspection;
+
+pub use client::SqliteClient;
@@ -0,0 +1,31 @@
+use sqlx::mysql::MySqlDatabaseError;
+
+/// Names the limit that cut a statement short, if it failed because a configured timeout
+/// expired rather than because of the statement itself.
+pub fn timeout_cause(error: &anyhow::Error) -> Option<&'static str> {
+    let error = error
+        .chain()
+        .find_map(|e| e.downcast_ref::<sqlx::Error>())?;
+    match error {
+        sqlx::Error::PoolTimedOut => Some("connect_timeout"),
+        sqlx::Error::Database(e) => {
+            if let Some(e) = e.try_downcast_ref::<MySqlDatabaseError>() {
+                // ER_QUERY_TIMEOUT and ER_LOCK_WAIT_TIMEOUT.
+                return match e.number() {
+                    3024 => Some("max_execution_time"),
+                    1205 => Some("innodb_lock_wait_timeout"),
+                    _ => None,
+                };
+            }
+            // query_canceled and lock_not_available also cover explicit cancels and NOWAIT.
+            match e.code().as_deref() {
+                Some("57014") if e.message().contains("statement timeout") => {
+                    Some("statement_timeout")
+                }
+                Some("55P03") if e.message().contains("lock timeout") => Some("lock_timeout"),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::anyhow;
+use sqlx::mysql::MySqlSslMode;
+use sqlx::postgres::PgSslMode;
+use std::io::ErrorKind;
+
+use crate::config::SslMode;
+
+pub fn pg_ssl_mode(mode: SslMode) -> PgSslMode {
+    match mode {
+        SslMode::Disable => PgSslMode::Disable,
+        SslMode::Allow => PgSslMode::Allow,
+        SslMode::Prefer => PgSslMode::Prefer,
+        SslMode::Require => PgSslMode::Require,
+        SslMode::VerifyCa => PgSslMode::VerifyCa,
+        SslMode::VerifyFull => PgSslMode::VerifyFull,
+    }
+}
+
+/// MySQL has no separate `allow` mode, so it is treated as `prefer`.
+pub fn mysql_ssl_mode(mode: SslMode) -> MySqlSslMode {
+    match mode {
+        SslMode::Disable => MySqlSslMode::Disabled,
+        SslMode::Allow | SslMode::Prefer => MySqlSslMode::Preferred,
+        SslMode::Require => MySqlSslMode::Required,
+        SslMode::VerifyCa => MySqlSslMode::VerifyCa,
+        SslMode::VerifyFull => MySqlSslMode::VerifyIdentity,
+    }
+}
+
+/// Marks handshake and certificate failures as TLS errors so they are not mistaken for
+/// network or authentication problems. rustls reports a rejected certificate as an
+/// `InvalidData` I/O error rather than through `sqlx::Error::Tls`.
+pub fn connection_error(error: sqlx::Error) -> anyhow::Error {
+    match error {
+        sqlx::Error::Tls(e) => anyhow!("TLS error: {}", e),
+        sqlx::Error::Io(e) if e.kind() == ErrorKind::InvalidData => anyhow!("TLS error: {}", e),
+        e => e.into(),
+    }
+}
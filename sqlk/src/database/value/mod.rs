@@ -0,0 +1,88 @@
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+/// A single decoded cell of a query result.
+///
+/// Backends decode their wire types into the closest variant so that rendering,
+/// statistics and foreign key lookups can tell a real SQL `NULL` apart from text
+/// that merely reads "NULL".
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    /// Exact numeric kept in its textual form so no precision is lost.
+    Decimal(String),
+    Text(String),
+    Bytes(Vec<u8>),
+    Json(serde_json::Value),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Timestamp(NaiveDateTime),
+    TimestampTz(DateTime<Utc>),
+    Uuid(uuid::Uuid),
+    /// Textual rendering of a type without a dedicated variant.
+    Other(String),
+}
+
+impl Value {
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    /// Placeholder for a value whose type the backend could not decode.
+    pub fn unsupported(type_name: &str) -> Self {
+        Value::Other(format!("<{}>", type_name))
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Value::Int(_) | Value::Float(_) | Value::Decimal(_))
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "NULL"),
+            Value::Bool(val) => write!(f, "{}", val),
+            Value::Int(val) => write!(f, "{}", val),
+            Value::Float(val) => write!(f, "{}", val),
+            Value::Decimal(val) | Value::Text(val) | Value::Other(val) => write!(f, "{}", val),
+            Value::Bytes(bytes) => {
+                write!(f, "\\x")?;
+                for byte in bytes {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+            Value::Json(val) => write!(f, "{}", val),
+            Value::Date(val) => write!(f, "{}", val.format("%Y-%m-%d")),
+            Value::Time(val) => write!(f, "{}", val.format("%H:%M:%S%.f")),
+            Value::Timestamp(val) => write!(f, "{}", val.format("%Y-%m-%d %H:%M:%S%.f")),
+            Value::TimestampTz(val) => write!(f, "{}", val.format("%Y-%m-%d %H:%M:%S%.f %Z")),
+            Value::Uuid(val) => write!(f, "{}", val),
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl From<f32> for Value {
+    /// Widens through the shortest decimal representation so `1.1f32` reads as `1.1`
+    /// rather than `1.100000023841858`.
+    fn from(value: f32) -> Self {
+        Value::Float(value.to_string().parse().unwrap_or(value as f64))
+    }
+}
@@ -1,8 +1,11 @@
 pub mod application;
 pub mod args;
+pub mod channel_monitor;
 pub mod config;
 pub mod database;
 pub mod matrix;
 pub mod query_parser;
+pub mod query_plan;
+pub mod schema_browser;
 pub mod table_viewer;
 pub mod ui;
@@ -1,5 +1,25 @@
 use regex::Regex;
 
+use crate::query_parser::scan::mask_literals;
+use crate::query_parser::utils::StringUtils;
+
+/// Leading keywords of statements that return a result set.
+const QUERY_KEYWORDS: &[&str] = &[
+    "SELECT", "WITH", "VALUES", "TABLE", "SHOW", "EXPLAIN", "PRAGMA", "DESCRIBE", "DESC",
+];
+
+/// Words between `CREATE`/`ALTER`/`DROP` and the kind of object they act on.
+const OBJECT_MODIFIERS: &[&str] = &[
+    "OR",
+    "REPLACE",
+    "UNIQUE",
+    "TEMP",
+    "TEMPORARY",
+    "UNLOGGED",
+    "GLOBAL",
+    "LOCAL",
+];
+
 pub struct QueryAnalyzer;
 
 impl QueryAnalyzer {
@@ -48,6 +68,76 @@ impl QueryAnalyzer {
         }
     }
 
+    /// Tag for a statement that is not a query, e.g. `UPDATE` or `CREATE TABLE`.
+    pub fn command_tag(&self, query: &str) -> Option<String> {
+        let cleaned = StringUtils::clean_query(query);
+        let mut words = cleaned
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ';')
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_uppercase());
+        let command = words.next()?;
+        if QUERY_KEYWORDS.contains(&command.as_str()) {
+            return None;
+        }
+
+        if !matches!(command.as_str(), "CREATE" | "ALTER" | "DROP") {
+            return Some(command);
+        }
+        let object = match words.find(|word| !OBJECT_MODIFIERS.contains(&word.as_str())) {
+            Some(object) if object == "MATERIALIZED" => match words.next() {
+                Some(kind) => format!("MATERIALIZED {}", kind),
+                None => object,
+            },
+            Some(object) => object,
+            None => return Some(command),
+        };
+        Some(format!("{} {}", command, object))
+    }
+
+    /// Whether the block opens or closes a transaction. A block that ends with `COMMIT` or
+    /// `ROLLBACK` closes it, even if it also begins one. Keywords inside string literals,
+    /// comments and dollar-quoted bodies are ignored.
+    pub fn transaction_control(&self, query: &str) -> Option<TransactionControl> {
+        let statements: Vec<Vec<String>> = mask_literals(query, false)
+            .split(';')
+            .map(|statement| {
+                statement
+                    .split_whitespace()
+                    .map(|word| word.to_uppercase())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|words| !words.is_empty())
+            .collect();
+
+        let ends = |words: &[String]| {
+            matches!(words[0].as_str(), "COMMIT" | "END" | "ROLLBACK" | "ABORT")
+                && words[1..]
+                    .iter()
+                    .all(|word| word == "WORK" || word == "TRANSACTION")
+        };
+        let begins = |words: &[String]| {
+            words[0] == "BEGIN"
+                || (words[0] == "START" && words.get(1).is_some_and(|word| word == "TRANSACTION"))
+        };
+
+        if statements.last().is_some_and(|words| ends(words)) {
+            Some(TransactionControl::End)
+        } else if statements.first().is_some_and(|words| begins(words)) {
+            Some(TransactionControl::Begin)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the block holds more than one statement, which a prepared statement cannot.
+    pub fn is_multi_statement(&self, query: &str, mysql_syntax: bool) -> bool {
+        mask_literals(query, mysql_syntax)
+            .split(';')
+            .filter(|statement| !statement.trim().is_empty())
+            .nth(1)
+            .is_some()
+    }
+
     fn is_valid_table_name(&self, table: &str) -> bool {
         ![
             "select", "where", "group", "order", "having", "limit", "offset", "union", "with",
@@ -68,6 +158,12 @@ pub enum QueryType {
     Other,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransactionControl {
+    Begin,
+    End,
+}
+
 impl Default for QueryAnalyzer {
     fn default() -> Self {
         Self::new()
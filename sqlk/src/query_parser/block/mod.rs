@@ -1,3 +1,5 @@
+use crate::query_parser::placeholder::{find_placeholders, placeholder_names};
+
 /// Represents a block of executable SQL text found in a file.
 #[derive(Debug, Clone)]
 pub struct QueryBlock {
@@ -7,14 +9,18 @@ pub struct QueryBlock {
     pub start_line: usize,
     /// The ending line number of the query block in the file (1-based).
     pub end_line: usize,
+    /// Distinct `$1`, `:name` and `@name` placeholders, in order of first appearance.
+    pub placeholders: Vec<String>,
 }
 
 impl QueryBlock {
     pub fn new(text: String, start_line: usize, end_line: usize) -> Self {
+        let placeholders = placeholder_names(&find_placeholders(&text));
         Self {
             text,
             start_line,
             end_line,
+            placeholders,
         }
     }
 
@@ -1,7 +1,9 @@
 pub mod analyzer;
 pub mod block;
 pub mod parser;
+pub mod placeholder;
 pub mod safety;
+pub mod scan;
 pub mod utils;
 
 pub use analyzer::QueryAnalyzer;
@@ -0,0 +1,102 @@
+use std::ops::Range;
+
+use crate::query_parser::scan::{is_word_byte, scan_while, skip_dollar_quoted, skip_quoted};
+
+/// One occurrence of a `$1`, `:name` or `@name` placeholder in query text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placeholder {
+    /// The placeholder as written, sigil included, e.g. `:user_id`.
+    pub name: String,
+    pub range: Range<usize>,
+}
+
+/// Finds placeholders outside string literals, quoted identifiers, comments and dollar-quoted
+/// bodies. `::` casts, `:=` assignments and `@@` system variables are not placeholders.
+pub fn find_placeholders(sql: &str) -> Vec<Placeholder> {
+    let bytes = sql.as_bytes();
+    let mut placeholders = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' | b'"' | b'`' => i = skip_quoted(bytes, i, false),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = sql[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = sql[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+            }
+            b'$' if bytes.get(i + 1).is_some_and(u8::is_ascii_digit) && !follows_word(bytes, i) => {
+                let end = scan_while(bytes, i + 1, |b| b.is_ascii_digit());
+                placeholders.push(placeholder(sql, i..end));
+                i = end;
+            }
+            b'$' => i = skip_dollar_quoted(sql, i),
+            b':' if bytes.get(i + 1) == Some(&b':') => i += 2,
+            b'@' if bytes.get(i + 1) == Some(&b'@') => {
+                i = scan_while(bytes, i + 2, is_word_byte);
+            }
+            b':' | b'@'
+                if bytes
+                    .get(i + 1)
+                    .is_some_and(|b| b.is_ascii_alphabetic() || *b == b'_')
+                    && !follows_word(bytes, i) =>
+            {
+                let end = scan_while(bytes, i + 1, is_word_byte);
+                placeholders.push(placeholder(sql, i..end));
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    placeholders
+}
+
+/// Distinct placeholder names in order of first appearance.
+pub fn placeholder_names(placeholders: &[Placeholder]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for placeholder in placeholders {
+        if !names.contains(&placeholder.name) {
+            names.push(placeholder.name.clone());
+        }
+    }
+    names
+}
+
+/// Rewrites each placeholder as `render(position, name)`, where `position` is the 1-based
+/// index of its name in [`placeholder_names`].
+pub fn replace_placeholders(
+    sql: &str,
+    placeholders: &[Placeholder],
+    render: impl Fn(usize, &str) -> String,
+) -> String {
+    let names = placeholder_names(placeholders);
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut last = 0;
+    for placeholder in placeholders {
+        let position = names
+            .iter()
+            .position(|name| *name == placeholder.name)
+            .unwrap_or(0)
+            + 1;
+        rewritten.push_str(&sql[last..placeholder.range.start]);
+        rewritten.push_str(&render(position, &placeholder.name));
+        last = placeholder.range.end;
+    }
+    rewritten.push_str(&sql[last..]);
+    rewritten
+}
+
+fn placeholder(sql: &str, range: Range<usize>) -> Placeholder {
+    Placeholder {
+        name: sql[range.clone()].to_string(),
+        range,
+    }
+}
+
+/// True when the byte before `i` continues an identifier, as in `a:b` or `x$1`.
+fn follows_word(bytes: &[u8], i: usize) -> bool {
+    i > 0 && (is_word_byte(bytes[i - 1]) || bytes[i - 1] == b'$')
+}
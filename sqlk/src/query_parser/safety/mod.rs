@@ -1,5 +1,38 @@
 use regex::Regex;
 
+use crate::query_parser::scan::mask_literals;
+
+/// Leading keywords of statements allowed on a read-only connection.
+const READ_ONLY_COMMANDS: &[&str] = &[
+    "SELECT",
+    "WITH",
+    "VALUES",
+    "TABLE",
+    "SHOW",
+    "EXPLAIN",
+    "PRAGMA",
+    "DESCRIBE",
+    "DESC",
+    "SET",
+    "USE",
+    "BEGIN",
+    "START",
+    "COMMIT",
+    "END",
+    "ROLLBACK",
+    "ABORT",
+    "SAVEPOINT",
+    "RELEASE",
+    "DECLARE",
+    "FETCH",
+    "MOVE",
+    "CLOSE",
+];
+
+/// Words that make an otherwise allowed statement write, as in a data-modifying `WITH`,
+/// `SELECT ... INTO` or `EXPLAIN ANALYZE DELETE`.
+const WRITE_WORDS: &[&str] = &["INSERT", "UPDATE", "DELETE", "MERGE", "INTO", "SET_CONFIG"];
+
 pub struct SafetyChecker;
 
 impl SafetyChecker {
@@ -76,6 +109,19 @@ impl SafetyChecker {
         warnings
     }
 
+    /// Why `query` may not run on a read-only connection, or `None` if it only reads.
+    /// Statements are allowed by their leading keyword, so anything unrecognized is refused;
+    /// the server's read-only mode still catches writes this cannot see, such as those made
+    /// inside functions.
+    pub fn read_only_violation(&self, query: &str) -> Option<String> {
+        // Text one backend reads as a string the other may run, so both readings must pass.
+        [false, true].into_iter().find_map(|mysql_syntax| {
+            mask_literals(query, mysql_syntax)
+                .split(';')
+                .find_map(statement_violation)
+        })
+    }
+
     fn contains_pattern(&self, query: &str, pattern: &str) -> bool {
         if let Ok(re) = Regex::new(pattern) {
             re.is_match(query)
@@ -99,3 +145,41 @@ impl Default for SafetyChecker {
         Self::new()
     }
 }
+
+fn statement_violation(statement: &str) -> Option<String> {
+    let words: Vec<String> = statement
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_uppercase)
+        .collect();
+    let has = |word: &str| words.iter().any(|w| w == word);
+    let command = words.first()?.as_str();
+
+    match command {
+        _ if !READ_ONLY_COMMANDS.contains(&command) => {
+            Some(format!("{} statements are not allowed", command))
+        }
+        "START" if words.get(1).map(String::as_str) != Some("TRANSACTION") => {
+            Some("START is only allowed as START TRANSACTION".to_string())
+        }
+        "BEGIN" | "START" if has("WRITE") => {
+            Some("READ WRITE transactions are not allowed".to_string())
+        }
+        "SET"
+            if has("WRITE")
+                || has("GLOBAL")
+                || has("PERSIST")
+                || words.iter().any(|w| w.contains("READ_ONLY")) =>
+        {
+            Some("SET cannot change read-only mode or server-wide settings".to_string())
+        }
+        "PRAGMA" if statement.contains('=') || (has("QUERY_ONLY") && statement.contains('(')) => {
+            Some("PRAGMA can only read settings".to_string())
+        }
+        "EXPLAIN" if !has("ANALYZE") && !has("ANALYSE") => None,
+        _ => WRITE_WORDS
+            .iter()
+            .find(|word| has(word))
+            .map(|word| format!("{} inside {} is not allowed", word, command)),
+    }
+}
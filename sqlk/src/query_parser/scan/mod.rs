@@ -0,0 +1,75 @@
+/// `sql` with string literals, quoted identifiers and comments blanked out, so the words in
+/// them are not read as SQL. `mysql_syntax` honours backslash escapes and `#` comments
+/// instead of dollar quoting.
+pub fn mask_literals(sql: &str, mysql_syntax: bool) -> String {
+    let bytes = sql.as_bytes();
+    let mut masked = String::with_capacity(sql.len());
+    let mut last = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let end = match bytes[i] {
+            b'\'' | b'"' | b'`' => skip_quoted(bytes, i, mysql_syntax),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => line_end(sql, i),
+            b'#' if mysql_syntax => line_end(sql, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => sql[i + 2..]
+                .find("*/")
+                .map_or(bytes.len(), |end| i + 2 + end + 2),
+            b'$' if !mysql_syntax => skip_dollar_quoted(sql, i),
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        masked.push_str(&sql[last..i]);
+        masked.push(' ');
+        i = end;
+        last = end;
+    }
+    masked.push_str(&sql[last..]);
+    masked
+}
+
+fn line_end(sql: &str, start: usize) -> usize {
+    sql[start..].find('\n').map_or(sql.len(), |end| start + end)
+}
+
+/// Skips a quoted string or identifier, where a doubled quote is an escaped one and, with
+/// `backslash_escapes` as in MySQL, so is anything after a backslash.
+pub fn skip_quoted(bytes: &[u8], start: usize, backslash_escapes: bool) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if backslash_escapes => i += 2,
+            b if b == quote && bytes.get(i + 1) == Some(&quote) => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Skips a PostgreSQL `$tag$ ... $tag$` body, or just the `$` when it does not open one.
+pub fn skip_dollar_quoted(sql: &str, start: usize) -> usize {
+    let bytes = sql.as_bytes();
+    let tag_end = scan_while(bytes, start + 1, is_word_byte);
+    if bytes.get(tag_end) != Some(&b'$') {
+        return start + 1;
+    }
+    let tag = &sql[start..=tag_end];
+    sql[tag_end + 1..]
+        .find(tag)
+        .map_or(bytes.len(), |end| tag_end + 1 + end + tag.len())
+}
+
+pub fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+pub fn scan_while(bytes: &[u8], mut i: usize, keep: impl Fn(u8) -> bool) -> usize {
+    while i < bytes.len() && keep(bytes[i]) {
+        i += 1;
+    }
+    i
+}
@@ -0,0 +1,478 @@
+use anyhow::{Result, anyhow};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// Nodes taking at least this share of the plan's own time (or cost) are highlighted as hot.
+pub const HOT_SHARE: f64 = 0.3;
+/// Nodes taking at least this share are highlighted as warm.
+pub const WARM_SHARE: f64 = 0.1;
+
+/// Plan keys left out of a node's details, mostly because its summary line shows them.
+const OMITTED_KEYS: &[&str] = &[
+    "Plans",
+    "Async Capable",
+    "Node Type",
+    "Join Type",
+    "Strategy",
+    "Parallel Aware",
+    "Scan Direction",
+    "Index Name",
+    "Relation Name",
+    "CTE Name",
+    "Function Name",
+    "Alias",
+    "Parent Relationship",
+    "Subplan Name",
+    "Startup Cost",
+    "Total Cost",
+    "Plan Rows",
+    "Actual Startup Time",
+    "Actual Total Time",
+    "Actual Rows",
+    "Actual Loops",
+    "Shared Hit Blocks",
+    "Shared Read Blocks",
+    "Shared Dirtied Blocks",
+    "Shared Written Blocks",
+    "Local Hit Blocks",
+    "Local Read Blocks",
+    "Local Dirtied Blocks",
+    "Local Written Blocks",
+    "Temp Read Blocks",
+    "Temp Written Blocks",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainMode {
+    /// The planner's estimates only; the statement is not run.
+    Estimate,
+    /// `ANALYZE, BUFFERS`: the statement runs, inside a transaction that is rolled back.
+    Analyze,
+}
+
+impl ExplainMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExplainMode::Estimate => "EXPLAIN",
+            ExplainMode::Analyze => "EXPLAIN ANALYZE",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanHeat {
+    Hot,
+    Warm,
+    Normal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActualStats {
+    pub startup_time: f64,
+    pub total_time: f64,
+    /// Rows per loop, as the server reports them.
+    pub rows: f64,
+    pub loops: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Buffers {
+    pub shared_hit: u64,
+    pub shared_read: u64,
+    pub shared_dirtied: u64,
+    pub shared_written: u64,
+    pub local_hit: u64,
+    pub local_read: u64,
+    pub temp_read: u64,
+    pub temp_written: u64,
+}
+
+impl Buffers {
+    /// The counters in `EXPLAIN` text form, e.g. `shared hit=12 read=3, temp read=40`.
+    pub fn summary(&self) -> String {
+        let groups = [
+            (
+                "shared",
+                vec![
+                    ("hit", self.shared_hit),
+                    ("read", self.shared_read),
+                    ("dirtied", self.shared_dirtied),
+                    ("written", self.shared_written),
+                ],
+            ),
+            (
+                "local",
+                vec![("hit", self.local_hit), ("read", self.local_read)],
+            ),
+            (
+                "temp",
+                vec![("read", self.temp_read), ("written", self.temp_written)],
+            ),
+        ];
+        let parts: Vec<String> = groups
+            .into_iter()
+            .filter_map(|(scope, counters)| {
+                let counters: Vec<String> = counters
+                    .into_iter()
+                    .filter(|(_, count)| *count > 0)
+                    .map(|(name, count)| format!("{}={}", name, count))
+                    .collect();
+                (!counters.is_empty()).then(|| format!("{} {}", scope, counters.join(" ")))
+            })
+            .collect();
+        if parts.is_empty() {
+            "none".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// One plan node, stored in depth-first order with its depth so the tree can be drawn flat.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanNode {
+    pub depth: usize,
+    /// The node as `EXPLAIN` text names it, e.g. `Index Scan using users_pkey on users u`.
+    pub label: String,
+    /// Set for init plans and sub plans, e.g. `SubPlan 1`.
+    pub subplan: Option<String>,
+    pub startup_cost: f64,
+    pub total_cost: f64,
+    pub plan_rows: f64,
+    pub actual: Option<ActualStats>,
+    pub buffers: Option<Buffers>,
+    /// Remaining plan keys, such as filters, sort keys and index conditions.
+    pub properties: Vec<(String, String)>,
+    /// Time in ms, or cost without `ANALYZE`, spent in this node excluding its children.
+    pub exclusive: f64,
+    /// `exclusive` as a fraction of the whole plan's.
+    pub share: f64,
+    /// Number of nodes below this one.
+    pub descendants: usize,
+}
+
+impl PlanNode {
+    pub fn heat(&self) -> PlanHeat {
+        if self.share >= HOT_SHARE {
+            PlanHeat::Hot
+        } else if self.share >= WARM_SHARE {
+            PlanHeat::Warm
+        } else {
+            PlanHeat::Normal
+        }
+    }
+
+    /// How far the actual row count is off the estimate, as a factor of at least 1.
+    pub fn misestimate(&self) -> Option<f64> {
+        let actual = self.actual.as_ref()?;
+        if actual.loops == 0.0 {
+            return None;
+        }
+        let estimated = self.plan_rows.max(1.0);
+        let actual = actual.rows.max(1.0);
+        Some((estimated / actual).max(actual / estimated))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryPlan {
+    pub query: String,
+    pub mode: ExplainMode,
+    pub nodes: Vec<PlanNode>,
+    pub planning_time: Option<f64>,
+    pub execution_time: Option<f64>,
+    /// One line per trigger fired while the statement ran.
+    pub triggers: Vec<String>,
+}
+
+impl QueryPlan {
+    /// Reads the output of `EXPLAIN (FORMAT JSON)`.
+    pub fn from_json(json: &Value, mode: ExplainMode, query: String) -> Result<Self> {
+        let explained = json
+            .as_array()
+            .and_then(|statements| statements.first())
+            .unwrap_or(json);
+        let root = explained
+            .get("Plan")
+            .and_then(Value::as_object)
+            .ok_or_else(|| anyhow!("EXPLAIN output has no plan"))?;
+
+        let mut nodes = Vec::new();
+        push_node(root, 0, mode, &mut nodes);
+        let total: f64 = nodes.iter().map(|node| node.exclusive).sum();
+        if total > 0.0 {
+            for node in &mut nodes {
+                node.share = node.exclusive / total;
+            }
+        }
+
+        let triggers = explained
+            .get("Triggers")
+            .and_then(Value::as_array)
+            .map(|triggers| {
+                triggers
+                    .iter()
+                    .map(|trigger| {
+                        format!(
+                            "{} on {}: {:.3} ms, {} calls",
+                            text(trigger.get("Trigger Name")),
+                            text(trigger.get("Relation")),
+                            number(trigger.get("Time")).unwrap_or(0.0),
+                            number(trigger.get("Calls")).unwrap_or(0.0)
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            query,
+            mode,
+            nodes,
+            planning_time: number(explained.get("Planning Time")),
+            execution_time: number(explained.get("Execution Time")),
+            triggers,
+        })
+    }
+}
+
+/// Appends `plan` and its children depth-first, returning the node's inclusive time or cost.
+fn push_node(
+    plan: &Map<String, Value>,
+    depth: usize,
+    mode: ExplainMode,
+    nodes: &mut Vec<PlanNode>,
+) -> f64 {
+    let actual = match (
+        number(plan.get("Actual Total Time")),
+        number(plan.get("Actual Loops")),
+    ) {
+        (Some(total_time), Some(loops)) => Some(ActualStats {
+            startup_time: number(plan.get("Actual Startup Time")).unwrap_or(0.0),
+            total_time,
+            rows: number(plan.get("Actual Rows")).unwrap_or(0.0),
+            loops,
+        }),
+        _ => None,
+    };
+    let total_cost = number(plan.get("Total Cost")).unwrap_or(0.0);
+    let inclusive = match (&actual, mode) {
+        (Some(actual), ExplainMode::Analyze) => actual.total_time * actual.loops,
+        _ => total_cost,
+    };
+
+    let index = nodes.len();
+    nodes.push(PlanNode {
+        depth,
+        label: node_label(plan),
+        subplan: plan
+            .get("Subplan Name")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        startup_cost: number(plan.get("Startup Cost")).unwrap_or(0.0),
+        total_cost,
+        plan_rows: number(plan.get("Plan Rows")).unwrap_or(0.0),
+        actual,
+        buffers: buffers(plan),
+        properties: plan
+            .iter()
+            .filter(|(key, _)| !OMITTED_KEYS.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), property_text(value)))
+            .collect(),
+        exclusive: 0.0,
+        share: 0.0,
+        descendants: 0,
+    });
+
+    let children: f64 = plan
+        .get("Plans")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object)
+        .map(|child| push_node(child, depth + 1, mode, nodes))
+        .sum();
+    let descendants = nodes.len() - index - 1;
+    let node = &mut nodes[index];
+    node.descendants = descendants;
+    node.exclusive = (inclusive - children).max(0.0);
+    inclusive
+}
+
+/// Names the node the way the text format does, e.g. `Hash Left Join` or
+/// `Index Scan Backward using idx on t`.
+fn node_label(plan: &Map<String, Value>) -> String {
+    let node_type = plan.get("Node Type").and_then(Value::as_str).unwrap_or("?");
+    let mut label = match (node_type, plan.get("Strategy").and_then(Value::as_str)) {
+        ("Aggregate", Some("Hashed")) => "HashAggregate".to_string(),
+        ("Aggregate", Some("Sorted")) => "GroupAggregate".to_string(),
+        ("Aggregate", Some("Mixed")) => "MixedAggregate".to_string(),
+        _ => node_type.to_string(),
+    };
+    if let Some(join_type) = plan.get("Join Type").and_then(Value::as_str)
+        && join_type != "Inner"
+    {
+        label = match label.strip_suffix(" Join") {
+            Some(method) => format!("{} {} Join", method, join_type),
+            None => format!("{} {} Join", label, join_type),
+        };
+    }
+    if plan.get("Parallel Aware").and_then(Value::as_bool) == Some(true) {
+        label = format!("Parallel {}", label);
+    }
+    if plan.get("Scan Direction").and_then(Value::as_str) == Some("Backward") {
+        label.push_str(" Backward");
+    }
+    if let Some(index) = plan.get("Index Name").and_then(Value::as_str) {
+        label.push_str(&format!(" using {}", index));
+    }
+
+    let alias = plan.get("Alias").and_then(Value::as_str);
+    let target = ["Relation Name", "CTE Name", "Function Name"]
+        .iter()
+        .find_map(|key| plan.get(*key).and_then(Value::as_str));
+    match (target, alias) {
+        (Some(target), Some(alias)) if alias != target => {
+            label.push_str(&format!(" on {} {}", target, alias))
+        }
+        (Some(target), _) | (None, Some(target)) => label.push_str(&format!(" on {}", target)),
+        (None, None) => {}
+    }
+    label
+}
+
+fn buffers(plan: &Map<String, Value>) -> Option<Buffers> {
+    plan.get("Shared Hit Blocks")?;
+    let count = |key: &str| number(plan.get(key)).unwrap_or(0.0) as u64;
+    Some(Buffers {
+        shared_hit: count("Shared Hit Blocks"),
+        shared_read: count("Shared Read Blocks"),
+        shared_dirtied: count("Shared Dirtied Blocks"),
+        shared_written: count("Shared Written Blocks"),
+        local_hit: count("Local Hit Blocks"),
+        local_read: count("Local Read Blocks"),
+        temp_read: count("Temp Read Blocks"),
+        temp_written: count("Temp Written Blocks"),
+    })
+}
+
+fn number(value: Option<&Value>) -> Option<f64> {
+    value?.as_f64()
+}
+
+fn text(value: Option<&Value>) -> String {
+    value.map(property_text).unwrap_or_default()
+}
+
+fn property_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(property_text)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+/// A plan shown as a collapsible tree, one line per visible node.
+#[derive(Debug, Clone)]
+pub struct PlanViewer {
+    pub plan: QueryPlan,
+    pub selected: usize,
+    collapsed: HashSet<usize>,
+}
+
+impl PlanViewer {
+    pub fn new(plan: QueryPlan) -> Self {
+        Self {
+            plan,
+            selected: 0,
+            collapsed: HashSet::new(),
+        }
+    }
+
+    /// Indices into `plan.nodes` of the nodes not hidden under a collapsed parent.
+    pub fn visible_nodes(&self) -> Vec<usize> {
+        let mut visible = Vec::new();
+        let mut idx = 0;
+        while idx < self.plan.nodes.len() {
+            visible.push(idx);
+            if self.collapsed.contains(&idx) {
+                idx += self.plan.nodes[idx].descendants;
+            }
+            idx += 1;
+        }
+        visible
+    }
+
+    pub fn is_collapsed(&self, node: usize) -> bool {
+        self.collapsed.contains(&node)
+    }
+
+    pub fn selected_node(&self) -> Option<&PlanNode> {
+        let idx = *self.visible_nodes().get(self.selected)?;
+        self.plan.nodes.get(idx)
+    }
+
+    pub fn move_down(&mut self, count: usize) {
+        let last = self.visible_nodes().len().saturating_sub(1);
+        self.selected = (self.selected + count).min(last);
+    }
+
+    pub fn move_up(&mut self, count: usize) {
+        self.selected = self.selected.saturating_sub(count);
+    }
+
+    pub fn select_last(&mut self) {
+        self.selected = self.visible_nodes().len().saturating_sub(1);
+    }
+
+    /// Moves to the next node at least as hot as warm, wrapping around.
+    pub fn select_next_expensive(&mut self) {
+        let visible = self.visible_nodes();
+        let next = (1..=visible.len())
+            .map(|offset| (self.selected + offset) % visible.len())
+            .find(|&position| self.plan.nodes[visible[position]].heat() != PlanHeat::Normal);
+        if let Some(position) = next {
+            self.selected = position;
+        }
+    }
+
+    pub fn expand(&mut self) {
+        if let Some(&idx) = self.visible_nodes().get(self.selected) {
+            self.collapsed.remove(&idx);
+        }
+    }
+
+    /// Collapses the selected node, or moves to its parent if it is already closed or a leaf.
+    pub fn collapse(&mut self) {
+        let visible = self.visible_nodes();
+        let Some(&idx) = visible.get(self.selected) else {
+            return;
+        };
+        let node = &self.plan.nodes[idx];
+        if node.descendants > 0 && !self.collapsed.contains(&idx) {
+            self.collapsed.insert(idx);
+            return;
+        }
+        let parent = self.plan.nodes[..idx]
+            .iter()
+            .rposition(|candidate| candidate.depth + 1 == node.depth);
+        if let Some(parent) = parent
+            && let Some(position) = visible.iter().position(|&visible| visible == parent)
+        {
+            self.selected = position;
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        if let Some(&idx) = self.visible_nodes().get(self.selected)
+            && self.plan.nodes[idx].descendants > 0
+            && !self.collapsed.remove(&idx)
+        {
+            self.collapsed.insert(idx);
+        }
+    }
+}
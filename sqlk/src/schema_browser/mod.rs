@@ -0,0 +1,217 @@
+use std::collections::HashSet;
+
+use crate::database::{SchemaInfo, TableInfo};
+
+/// Rows fetched when previewing a table from the browser.
+pub const PREVIEW_ROW_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableSection {
+    Columns,
+    Indexes,
+    Constraints,
+}
+
+impl TableSection {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TableSection::Columns => "Columns",
+            TableSection::Indexes => "Indexes",
+            TableSection::Constraints => "Constraints",
+        }
+    }
+}
+
+/// One visible line of the tree, addressed by indices into [`SchemaBrowser::schemas`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserNode {
+    Schema(usize),
+    Table(usize, usize),
+    Section(usize, usize, TableSection),
+    Column(usize, usize, usize),
+    Index(usize, usize, usize),
+    Constraint(usize, usize, usize),
+}
+
+impl BrowserNode {
+    pub fn depth(&self) -> usize {
+        match self {
+            BrowserNode::Schema(_) => 0,
+            BrowserNode::Table(..) => 1,
+            BrowserNode::Section(..) => 2,
+            BrowserNode::Column(..) | BrowserNode::Index(..) | BrowserNode::Constraint(..) => 3,
+        }
+    }
+
+    /// The table this node belongs to, if it is not a schema.
+    pub fn table(&self) -> Option<(usize, usize)> {
+        match *self {
+            BrowserNode::Schema(_) => None,
+            BrowserNode::Table(schema, table)
+            | BrowserNode::Section(schema, table, _)
+            | BrowserNode::Column(schema, table, _)
+            | BrowserNode::Index(schema, table, _)
+            | BrowserNode::Constraint(schema, table, _) => Some((schema, table)),
+        }
+    }
+}
+
+/// Tree of schemas → tables → columns, indexes and constraints.
+#[derive(Debug, Clone)]
+pub struct SchemaBrowser {
+    pub schemas: Vec<SchemaInfo>,
+    pub selected: usize,
+    expanded_schemas: HashSet<usize>,
+    expanded_tables: HashSet<(usize, usize)>,
+}
+
+impl SchemaBrowser {
+    pub fn new(schemas: Vec<SchemaInfo>) -> Self {
+        // A lone schema starts open so its tables are visible straight away.
+        let expanded_schemas = if schemas.len() == 1 {
+            HashSet::from([0])
+        } else {
+            HashSet::new()
+        };
+        Self {
+            schemas,
+            selected: 0,
+            expanded_schemas,
+            expanded_tables: HashSet::new(),
+        }
+    }
+
+    pub fn visible_nodes(&self) -> Vec<BrowserNode> {
+        let mut nodes = Vec::new();
+        for (schema_idx, schema) in self.schemas.iter().enumerate() {
+            nodes.push(BrowserNode::Schema(schema_idx));
+            if !self.expanded_schemas.contains(&schema_idx) {
+                continue;
+            }
+            for (table_idx, table) in schema.tables.iter().enumerate() {
+                nodes.push(BrowserNode::Table(schema_idx, table_idx));
+                if !self.expanded_tables.contains(&(schema_idx, table_idx)) {
+                    continue;
+                }
+                nodes.push(BrowserNode::Section(
+                    schema_idx,
+                    table_idx,
+                    TableSection::Columns,
+                ));
+                nodes.extend(
+                    (0..table.columns.len())
+                        .map(|idx| BrowserNode::Column(schema_idx, table_idx, idx)),
+                );
+                if !table.indexes.is_empty() {
+                    nodes.push(BrowserNode::Section(
+                        schema_idx,
+                        table_idx,
+                        TableSection::Indexes,
+                    ));
+                    nodes.extend(
+                        (0..table.indexes.len())
+                            .map(|idx| BrowserNode::Index(schema_idx, table_idx, idx)),
+                    );
+                }
+                if !table.constraints.is_empty() {
+                    nodes.push(BrowserNode::Section(
+                        schema_idx,
+                        table_idx,
+                        TableSection::Constraints,
+                    ));
+                    nodes.extend(
+                        (0..table.constraints.len())
+                            .map(|idx| BrowserNode::Constraint(schema_idx, table_idx, idx)),
+                    );
+                }
+            }
+        }
+        nodes
+    }
+
+    pub fn is_expanded(&self, node: &BrowserNode) -> bool {
+        match *node {
+            BrowserNode::Schema(schema) => self.expanded_schemas.contains(&schema),
+            BrowserNode::Table(schema, table) => self.expanded_tables.contains(&(schema, table)),
+            _ => false,
+        }
+    }
+
+    pub fn selected_node(&self) -> Option<BrowserNode> {
+        self.visible_nodes().get(self.selected).copied()
+    }
+
+    /// The table under the cursor, including when a column or index of it is selected.
+    pub fn selected_table(&self) -> Option<&TableInfo> {
+        let (schema, table) = self.selected_node()?.table()?;
+        self.schemas.get(schema)?.tables.get(table)
+    }
+
+    pub fn move_down(&mut self, count: usize) {
+        let last = self.visible_nodes().len().saturating_sub(1);
+        self.selected = (self.selected + count).min(last);
+    }
+
+    pub fn move_up(&mut self, count: usize) {
+        self.selected = self.selected.saturating_sub(count);
+    }
+
+    pub fn select_last(&mut self) {
+        self.selected = self.visible_nodes().len().saturating_sub(1);
+    }
+
+    pub fn expand(&mut self) {
+        match self.selected_node() {
+            Some(BrowserNode::Schema(schema)) => {
+                self.expanded_schemas.insert(schema);
+            }
+            Some(BrowserNode::Table(schema, table)) => {
+                self.expanded_tables.insert((schema, table));
+            }
+            _ => {}
+        }
+    }
+
+    /// Collapses the selected node, or the nearest open node above it, and selects that.
+    pub fn collapse(&mut self) {
+        let Some(node) = self.selected_node() else {
+            return;
+        };
+        let target = match node {
+            BrowserNode::Schema(_) => node,
+            BrowserNode::Table(..) if self.is_expanded(&node) => node,
+            BrowserNode::Table(schema, _) => BrowserNode::Schema(schema),
+            BrowserNode::Section(schema, table, _)
+            | BrowserNode::Column(schema, table, _)
+            | BrowserNode::Index(schema, table, _)
+            | BrowserNode::Constraint(schema, table, _) => BrowserNode::Table(schema, table),
+        };
+        match target {
+            BrowserNode::Schema(schema) => {
+                self.expanded_schemas.remove(&schema);
+            }
+            BrowserNode::Table(schema, table) => {
+                self.expanded_tables.remove(&(schema, table));
+            }
+            _ => {}
+        }
+        self.select(target);
+    }
+
+    pub fn toggle(&mut self) {
+        match self.selected_node() {
+            Some(node) if self.is_expanded(&node) => self.collapse(),
+            _ => self.expand(),
+        }
+    }
+
+    fn select(&mut self, node: BrowserNode) {
+        if let Some(idx) = self
+            .visible_nodes()
+            .iter()
+            .position(|visible| *visible == node)
+        {
+            self.selected = idx;
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub struct ChartItem {
 }
 
 impl TableViewer {
+    #[allow(clippy::unnecessary_sort_by)]
     pub fn generate_chart_data(
         &self,
         max_items: usize,
@@ -8,6 +8,7 @@ use crate::database::DatabaseManager;
 use crate::database::ForeignKeyInfo;
 
 use crate::database::QueryResult;
+use crate::database::Value;
 use crate::table_viewer::ChartData;
 use crate::table_viewer::ColumnStats;
 use crate::table_viewer::ForeignKeyLookupResult;
@@ -37,7 +38,7 @@ pub struct CellPosition {
 #[derive(Debug)]
 pub struct TableViewData {
     pub headers: Vec<String>,
-    pub rows: Vec<Vec<String>>,
+    pub rows: Vec<Vec<Value>>,
     pub current_row_relative: usize,
     pub current_col_relative: usize,
     pub total_rows: usize,
@@ -65,6 +66,8 @@ pub struct TableViewer {
     pub column_stats: HashMap<usize, ColumnStats>,
     pub show_chart: bool,
     pub chart_data: Option<ChartData>,
+    /// Whether the server messages panel is open below the grid.
+    pub show_messages: bool,
 }
 
 impl TableViewer {
@@ -72,8 +75,10 @@ impl TableViewer {
         let mut foreign_keys = HashMap::new();
         let mut column_stats = HashMap::new();
 
-        for (idx, header) in data.headers.iter().enumerate() {
-            if let Ok(fk_info) = db_manager.get_foreign_key_info(header) {
+        for idx in 0..data.headers.len() {
+            if let Some(Some(column)) = data.column_sources.get(idx)
+                && let Some(fk_info) = db_manager.get_foreign_key_info(column)
+            {
                 foreign_keys.insert(idx, fk_info.clone());
             }
 
@@ -81,6 +86,7 @@ impl TableViewer {
             column_stats.insert(idx, stats);
         }
 
+        let show_messages = !data.notices.is_empty();
         Ok(Self {
             data,
             current_row: 0,
@@ -94,9 +100,28 @@ impl TableViewer {
             column_stats,
             show_chart: false,
             chart_data: None,
+            show_messages,
         })
     }
 
+    pub fn is_truncated(&self) -> bool {
+        self.data.truncated
+    }
+
+    /// Adds the next page of the same result below the rows already shown.
+    pub fn append_rows(&mut self, page: QueryResult) {
+        self.data.rows.extend(page.rows);
+        self.data.row_count = self.data.rows.len();
+        self.data.truncated = page.truncated;
+        self.column_stats = (0..self.data.headers.len())
+            .map(|idx| (idx, Self::compute_column_stats(&self.data, idx)))
+            .collect();
+        if self.show_chart {
+            self.show_chart = false;
+            self.chart_data = None;
+        }
+    }
+
     pub fn get_column_type(&self, col_idx: usize) -> Option<&String> {
         self.data.column_types.get(col_idx)
     }
@@ -106,11 +131,11 @@ impl TableViewer {
     }
 
     pub fn get_current_cell_value(&self) -> Option<String> {
-        self.data
-            .rows
-            .get(self.current_row)?
-            .get(self.current_col)
-            .cloned()
+        self.get_current_value().map(|value| value.to_string())
+    }
+
+    pub fn get_current_value(&self) -> Option<&Value> {
+        self.data.rows.get(self.current_row)?.get(self.current_col)
     }
 
     pub fn get_visible_data(&self, width: u16, height: u16) -> TableViewData {
@@ -201,7 +226,7 @@ impl TableViewer {
         }
     }
 
-    pub fn get_current_row_with_headers(&self) -> Option<(&Vec<String>, &Vec<String>)> {
+    pub fn get_current_row_with_headers(&self) -> Option<(&Vec<String>, &Vec<Value>)> {
         if let Some(row) = self.data.rows.get(self.current_row) {
             Some((&self.data.headers, row))
         } else {
@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use crate::{
     application::app::App,
-    database::{DatabaseManager, ForeignKeyInfo, QueryResult},
+    database::{DatabaseManager, ForeignKeyInfo, QueryResult, Value},
     table_viewer::TableViewer,
 };
 
@@ -12,18 +12,191 @@ pub struct ForeignKeyLookupResult {
     pub lookup_data: QueryResult,
 }
 
+/// An inbound foreign key to the current row, as listed by the references view.
+#[derive(Debug)]
+pub struct ReferencingKey {
+    pub foreign_key_info: ForeignKeyInfo,
+    pub key_values: Vec<Value>,
+    pub row_count: Result<i64, String>,
+}
+
+#[derive(Debug)]
+pub struct ReferencesView {
+    pub entries: Vec<ReferencingKey>,
+    pub selected: usize,
+}
+
+/// One hop of foreign key navigation: the column followed and the rows it led to.
+#[derive(Debug)]
+pub struct ForeignKeyStep {
+    pub source: String,
+    pub target: String,
+    pub viewer: TableViewer,
+}
+
+/// The chain of viewers opened by following foreign keys, with browser-style history.
+#[derive(Debug, Default)]
+pub struct ForeignKeyNavigation {
+    steps: Vec<ForeignKeyStep>,
+    position: usize,
+}
+
+impl ForeignKeyNavigation {
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn current(&self) -> Option<&TableViewer> {
+        self.steps.get(self.position).map(|step| &step.viewer)
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut TableViewer> {
+        self.steps
+            .get_mut(self.position)
+            .map(|step| &mut step.viewer)
+    }
+
+    /// Opens a new step after the current one, discarding any forward history.
+    pub fn push(&mut self, step: ForeignKeyStep) {
+        if !self.steps.is_empty() {
+            self.steps.truncate(self.position + 1);
+        }
+        self.steps.push(step);
+        self.position = self.steps.len() - 1;
+    }
+
+    pub fn back(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        self.position -= 1;
+        true
+    }
+
+    pub fn forward(&mut self) -> bool {
+        if self.position + 1 >= self.steps.len() {
+            return false;
+        }
+        self.position += 1;
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.steps.clear();
+        self.position = 0;
+    }
+
+    /// The trail up to the current step, e.g. `orders.user_id → users.org_id → orgs`.
+    pub fn breadcrumbs(&self) -> String {
+        let Some(current) = self.steps.get(self.position) else {
+            return String::new();
+        };
+        self.steps[..=self.position]
+            .iter()
+            .map(|step| step.source.as_str())
+            .chain(std::iter::once(current.target.as_str()))
+            .collect::<Vec<_>>()
+            .join(" → ")
+    }
+}
+
 impl TableViewer {
+    /// Collects the current row's value for every column of `fk_info`, in key order.
+    /// Returns `None` when any component is NULL, since such a key references nothing.
+    pub fn foreign_key_values(&self, fk_info: &ForeignKeyInfo) -> Result<Option<Vec<Value>>> {
+        self.key_values(&fk_info.schema, &fk_info.table_name, &fk_info.columns)
+    }
+
+    /// The current row's values for the columns `fk_info` points at.
+    pub fn referenced_key_values(&self, fk_info: &ForeignKeyInfo) -> Result<Option<Vec<Value>>> {
+        self.key_values(
+            &fk_info.referenced_schema,
+            &fk_info.referenced_table,
+            &fk_info.referenced_columns,
+        )
+    }
+
+    fn key_values(
+        &self,
+        schema: &str,
+        table: &str,
+        columns: &[String],
+    ) -> Result<Option<Vec<Value>>> {
+        let Some(row) = self.data.rows.get(self.current_row) else {
+            return Ok(None);
+        };
+
+        let mut values = Vec::with_capacity(columns.len());
+        for column in columns {
+            let idx = (0..self.data.headers.len())
+                .find(|idx| {
+                    matches!(
+                        self.data.column_sources.get(*idx),
+                        Some(Some(source)) if source.refers_to(schema, table, column)
+                    )
+                })
+                .or_else(|| {
+                    self.data
+                        .headers
+                        .iter()
+                        .position(|header| header.eq_ignore_ascii_case(column))
+                })
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Key column {}.{} is not in the result", table, column)
+                })?;
+
+            match row.get(idx) {
+                Some(value) if !value.is_null() => values.push(value.clone()),
+                _ => return Ok(None),
+            }
+        }
+
+        Ok(Some(values))
+    }
+
+    /// Lists the foreign keys pointing at the table of the current column, with how many
+    /// rows reference the current row through each.
+    pub async fn find_referencing_keys(
+        &self,
+        db_manager: &DatabaseManager,
+    ) -> Result<Vec<ReferencingKey>> {
+        let source = self
+            .data
+            .column_sources
+            .get(self.current_col)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("Cannot tell which table this column comes from"))?;
+
+        let mut referencing_keys = Vec::new();
+        for fk_info in
+            db_manager.get_referencing_foreign_keys(source.schema.as_deref(), &source.table)
+        {
+            let Ok(Some(key_values)) = self.referenced_key_values(fk_info) else {
+                continue;
+            };
+            let row_count = db_manager
+                .count_referencing_rows(fk_info, &key_values)
+                .await
+                .map_err(|e| e.to_string());
+            referencing_keys.push(ReferencingKey {
+                foreign_key_info: fk_info.clone(),
+                key_values,
+                row_count,
+            });
+        }
+
+        Ok(referencing_keys)
+    }
+
     pub async fn lookup_foreign_key_info(
         &self,
         app: &App,
     ) -> Result<Option<ForeignKeyLookupResult>> {
-        let current_cell_value = self.get_current_cell_value();
-        let header_name = self.data.headers.get(self.current_col).cloned();
-
-        if let (Some(value), Some(header)) = (current_cell_value, header_name) {
-            if value.is_empty() || value == "NULL" {
+        if let Some(fk_info) = self.foreign_keys.get(&self.current_col) {
+            let Ok(Some(values)) = self.foreign_key_values(fk_info) else {
                 return Ok(None);
-            }
+            };
 
             let db_man_clone = app.db_manager.clone();
             // Use `get_or_try_init` on the cloned `OnceCell` inside the spawned task.
@@ -36,16 +209,12 @@ impl TableViewer {
                 .map_err(|e| anyhow::anyhow!("Database manager initialization failed: {}", e))
                 .expect("Failed to get db_manager");
 
-            if let Some(fk_info) = self.foreign_keys.get(&self.current_col) {
-                match db_manager.lookup_foreign_key(&header, &value).await {
-                    Ok(lookup_result) => Ok(Some(ForeignKeyLookupResult {
-                        foreign_key_info: fk_info.clone(),
-                        lookup_data: lookup_result,
-                    })),
-                    Err(_) => Ok(None),
-                }
-            } else {
-                Ok(None)
+            match db_manager.lookup_foreign_key(fk_info, &values).await {
+                Ok(lookup_result) => Ok(Some(ForeignKeyLookupResult {
+                    foreign_key_info: fk_info.clone(),
+                    lookup_data: lookup_result,
+                })),
+                Err(_) => Ok(None),
             }
         } else {
             Ok(None)
@@ -53,13 +222,10 @@ impl TableViewer {
     }
 
     pub async fn lookup_foreign_key(&self, app: &App) -> Result<Option<TableViewer>> {
-        let current_cell_value = self.get_current_cell_value();
-        let header_name = self.data.headers.get(self.current_col).cloned();
-
-        if let (Some(value), Some(header)) = (current_cell_value, header_name) {
-            if value.is_empty() || value == "NULL" {
+        if let Some(fk_info) = self.foreign_keys.get(&self.current_col) {
+            let Some(values) = self.foreign_key_values(fk_info)? else {
                 return Ok(None);
-            }
+            };
 
             let db_man_clone = app.db_manager.clone();
             // Use `get_or_try_init` on the cloned `OnceCell` inside the spawned task.
@@ -72,7 +238,7 @@ impl TableViewer {
                 .map_err(|e| anyhow::anyhow!("Database manager initialization failed: {}", e))
                 .expect("Failed to get db_manager");
 
-            let result = db_manager.lookup_foreign_key(&header, &value).await?;
+            let result = db_manager.lookup_foreign_key(fk_info, &values).await?;
             let new_viewer = TableViewer::new(result, &app.config, db_manager)?;
             Ok(Some(new_viewer))
         } else {
@@ -7,6 +7,8 @@ pub mod statistics;
 
 pub use chart::{ChartData, ChartItem};
 pub use data::{CellInfo, CellPosition, TableViewData, TableViewer};
-pub use fk::ForeignKeyLookupResult;
+pub use fk::{
+    ForeignKeyLookupResult, ForeignKeyNavigation, ForeignKeyStep, ReferencesView, ReferencingKey,
+};
 pub use search::SearchState;
 pub use statistics::ColumnStats;
@@ -54,6 +54,9 @@ impl TableViewer {
                 let bar_width = 30;
                 self.toggle_chart(bar_width);
             }
+            KeyCode::Char('m') if !self.data.notices.is_empty() => {
+                self.show_messages = !self.show_messages;
+            }
 
             _ => {}
         }
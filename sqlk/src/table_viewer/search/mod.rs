@@ -28,7 +28,7 @@ impl TableViewer {
 
         for (row_idx, row) in self.data.rows.iter().enumerate() {
             for (col_idx, cell) in row.iter().enumerate() {
-                if re.is_match(cell) {
+                if !cell.is_null() && re.is_match(&cell.to_string()) {
                     self.search_state.matches.push(CellPosition {
                         row: row_idx,
                         col: col_idx,
@@ -22,10 +22,10 @@ impl TableViewer {
 
         for row in &data.rows {
             if let Some(cell_value) = row.get(col_idx) {
-                if cell_value.is_empty() || cell_value == "NULL" {
+                if cell_value.is_null() {
                     null_count += 1;
                 } else {
-                    *value_counts.entry(cell_value.clone()).or_insert(0) += 1;
+                    *value_counts.entry(cell_value.to_string()).or_insert(0) += 1;
                 }
             }
         }
@@ -43,6 +43,9 @@ impl TableViewer {
     }
 
     pub async fn get_cell_info(&self, app: &App) -> Result<CellInfo> {
+        let is_null = self
+            .get_current_value()
+            .is_some_and(|value| value.is_null());
         let current_value = self.get_current_cell_value().unwrap_or_default();
         let column_name = self
             .data
@@ -82,7 +85,7 @@ impl TableViewer {
             column_index: self.current_col,
             row_index: self.current_row,
             data_type,
-            is_null: current_value.is_empty() || current_value == "NULL",
+            is_null,
             value_length: current_value.len(),
             duplicate_count,
             unique_values_in_column,
@@ -0,0 +1,183 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::{
+    application::app::App,
+    schema_browser::{BrowserNode, SchemaBrowser},
+    ui::UI,
+};
+
+impl UI {
+    pub fn render_schema_browser(&self, f: &mut Frame, app: &App) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ])
+            .split(f.area());
+
+        let spinner_chars = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+        let spinner_char = spinner_chars[self.spinner_frame % spinner_chars.len()];
+
+        let header_text = match &app.schema_browser {
+            Some(_) if app.is_loading_schema => format!("{} Reloading schema...", spinner_char),
+            Some(browser) => {
+                let tables: usize = browser
+                    .schemas
+                    .iter()
+                    .map(|schema| schema.tables.len())
+                    .sum();
+                let schemas = browser.schemas.len();
+                format!(
+                    "{} schema{}, {} tables and views",
+                    schemas,
+                    if schemas == 1 { "" } else { "s" },
+                    tables
+                )
+            }
+            None => format!("{} Loading schema...", spinner_char),
+        };
+        let header = Paragraph::new(header_text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Schema Browser")
+                    .style(Style::default().fg(Color::Cyan)),
+            )
+            .alignment(Alignment::Center);
+        f.render_widget(header, chunks[0]);
+
+        if let Some(browser) = &app.schema_browser {
+            self.render_schema_tree(f, browser, chunks[1]);
+        } else {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White));
+            f.render_widget(block, chunks[1]);
+        }
+
+        self.render_footer(f, app, chunks[2]);
+    }
+
+    fn render_schema_tree(&self, f: &mut Frame, browser: &SchemaBrowser, area: Rect) {
+        let nodes = browser.visible_nodes();
+        let height = area.height.saturating_sub(2) as usize;
+        let start = (browser.selected + 1).saturating_sub(height);
+
+        let lines: Vec<Line> = nodes
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(height)
+            .map(|(idx, node)| {
+                let mut line = self.schema_node_line(browser, node);
+                if idx == browser.selected {
+                    line = line.style(Style::default().bg(Color::DarkGray));
+                }
+                line
+            })
+            .collect();
+
+        let tree = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White)),
+        );
+        f.render_widget(tree, area);
+    }
+
+    fn schema_node_line(&self, browser: &SchemaBrowser, node: &BrowserNode) -> Line<'static> {
+        let indent = "  ".repeat(node.depth());
+        let marker = if browser.is_expanded(node) {
+            "▾ "
+        } else {
+            "▸ "
+        };
+        let dim = Style::default().fg(Color::DarkGray);
+
+        match *node {
+            BrowserNode::Schema(schema) => {
+                let schema = &browser.schemas[schema];
+                Line::from(vec![
+                    Span::raw(marker),
+                    Span::styled(
+                        schema.name.clone(),
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!("  {} tables", schema.tables.len()), dim),
+                ])
+            }
+            BrowserNode::Table(schema, table) => {
+                let table = &browser.schemas[schema].tables[table];
+                let rows = table
+                    .estimated_rows
+                    .map(|rows| format!("  ~{} rows", rows))
+                    .unwrap_or_default();
+                Line::from(vec![
+                    Span::raw(format!("{}{}", indent, marker)),
+                    Span::styled(
+                        table.name.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(format!("  {}", table.kind.label()), dim),
+                    Span::styled(rows, Style::default().fg(Color::Green)),
+                ])
+            }
+            BrowserNode::Section(_, _, section) => Line::from(vec![
+                Span::raw(indent),
+                Span::styled(section.label(), Style::default().fg(Color::Yellow)),
+            ]),
+            BrowserNode::Column(schema, table, column) => {
+                let column = &browser.schemas[schema].tables[table].columns[column];
+                let mut spans = vec![
+                    Span::raw(indent),
+                    Span::raw(column.name.clone()),
+                    Span::styled(
+                        format!("  {}", column.data_type),
+                        Style::default().fg(Color::Magenta),
+                    ),
+                ];
+                if !column.nullable {
+                    spans.push(Span::styled("  NOT NULL", dim));
+                }
+                if let Some(default) = &column.default {
+                    spans.push(Span::styled(format!("  DEFAULT {}", default), dim));
+                }
+                Line::from(spans)
+            }
+            BrowserNode::Index(schema, table, index) => {
+                let index = &browser.schemas[schema].tables[table].indexes[index];
+                let kind = if index.primary {
+                    "  PRIMARY"
+                } else if index.unique {
+                    "  UNIQUE"
+                } else {
+                    ""
+                };
+                Line::from(vec![
+                    Span::raw(indent),
+                    Span::raw(index.name.clone()),
+                    Span::styled(format!(" ({})", index.columns.join(", ")), dim),
+                    Span::styled(kind, Style::default().fg(Color::Green)),
+                ])
+            }
+            BrowserNode::Constraint(schema, table, constraint) => {
+                let constraint = &browser.schemas[schema].tables[table].constraints[constraint];
+                Line::from(vec![
+                    Span::raw(indent),
+                    Span::raw(constraint.name.clone()),
+                    Span::styled(format!("  {}", constraint.definition), dim),
+                ])
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap},
+};
+
+use crate::{
+    application::app::App,
+    channel_monitor::ChannelMonitor,
+    ui::{UI, centered_rect},
+};
+
+impl UI {
+    pub fn render_channel_monitor(&self, f: &mut Frame, app: &App) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Percentage(35),
+                Constraint::Length(3),
+            ])
+            .split(f.area());
+
+        let Some(monitor) = &app.channel_monitor else {
+            self.render_footer(f, app, chunks[3]);
+            return;
+        };
+
+        let header = if monitor.is_listening() {
+            Line::from(vec![
+                Span::styled(
+                    "LISTEN ",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(monitor.channels.join(", ")),
+                Span::styled(
+                    format!("  {} received", monitor.notifications.len()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        } else {
+            Line::from(Span::styled(
+                "Not listening",
+                Style::default().fg(Color::DarkGray),
+            ))
+        };
+        let header = Paragraph::new(header).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Channel Monitor")
+                .style(Style::default().fg(Color::Cyan)),
+        );
+        f.render_widget(header, chunks[0]);
+
+        self.render_notification_table(f, monitor, chunks[1]);
+        self.render_notification_payload(f, monitor, chunks[2]);
+        self.render_footer(f, app, chunks[3]);
+
+        if let Some(input) = &monitor.input {
+            self.render_channel_input(f, input);
+        }
+    }
+
+    fn render_notification_table(&self, f: &mut Frame, monitor: &ChannelMonitor, area: Rect) {
+        // Borders and the header row.
+        let height = area.height.saturating_sub(3) as usize;
+        let start = (monitor.selected + 1).saturating_sub(height);
+
+        let rows: Vec<Row> = monitor
+            .notifications
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(height)
+            .map(|(idx, notification)| {
+                let style = if idx == monitor.selected {
+                    Style::default().bg(Color::DarkGray)
+                } else {
+                    Style::default()
+                };
+                Row::new(vec![
+                    Cell::from(notification.received_at.format("%H:%M:%S%.3f").to_string())
+                        .style(Style::default().fg(Color::DarkGray)),
+                    Cell::from(notification.channel.clone())
+                        .style(Style::default().fg(Color::Yellow)),
+                    Cell::from(notification.pid.to_string()),
+                    Cell::from(notification.payload.replace(['\n', '\r'], " ")),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let header = Row::new(vec!["Time", "Channel", "PID", "Payload"]).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(12),
+                Constraint::Length(20),
+                Constraint::Length(8),
+                Constraint::Min(0),
+            ],
+        )
+        .header(header)
+        .column_spacing(1)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White)),
+        );
+        f.render_widget(table, area);
+    }
+
+    fn render_notification_payload(&self, f: &mut Frame, monitor: &ChannelMonitor, area: Rect) {
+        let text = monitor
+            .selected_notification()
+            .map(|notification| notification.pretty_payload())
+            .unwrap_or_default();
+        let payload = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Payload")
+                .style(Style::default().fg(Color::White)),
+        );
+        f.render_widget(payload, area);
+    }
+
+    fn render_channel_input(&self, f: &mut Frame, input: &str) {
+        let area = centered_rect(60, 20, f.area());
+
+        let (text, style) = if input.is_empty() {
+            (
+                "Channels, separated by commas...",
+                Style::default().fg(Color::DarkGray),
+            )
+        } else {
+            (input, Style::default().fg(Color::White))
+        };
+        let prompt = Paragraph::new(text).style(style).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("LISTEN")
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        let prompt_area = Rect {
+            y: area.y + area.height.saturating_sub(3) / 2,
+            height: 3.min(area.height),
+            ..area
+        };
+        f.render_widget(Clear, prompt_area);
+        f.render_widget(prompt, prompt_area);
+        f.set_cursor_position(Position {
+            x: prompt_area.x + input.chars().count() as u16 + 1,
+            y: prompt_area.y + 1,
+        });
+    }
+}
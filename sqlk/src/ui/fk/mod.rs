@@ -39,11 +39,7 @@ impl UI {
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(
-                        format!(
-                            "{}.{}",
-                            fk_data.foreign_key_info.referenced_table,
-                            fk_data.foreign_key_info.referenced_column
-                        ),
+                        fk_data.foreign_key_info.target_display(),
                         Style::default().fg(Color::Cyan),
                     ),
                 ]),
@@ -78,16 +74,13 @@ impl UI {
                     .take(5)
                     .enumerate()
                     .map(|(i, row)| {
-                        let row_text = if row.len() > 3 {
-                            format!(
-                                "{} | {} | {} | ...",
-                                row.first().unwrap_or(&"".to_string()),
-                                row.get(1).unwrap_or(&"".to_string()),
-                                row.get(2).unwrap_or(&"".to_string())
-                            )
-                        } else {
-                            row.join(" | ")
-                        };
+                        let row_text = row
+                            .iter()
+                            .take(3)
+                            .map(|value| value.to_string())
+                            .collect::<Vec<String>>()
+                            .join(" | ")
+                            + if row.len() > 3 { " | ..." } else { "" };
 
                         Line::from(vec![
                             Span::styled(
@@ -134,7 +127,7 @@ impl UI {
         }
     }
     pub fn render_foreign_key_viewer(&self, f: &mut Frame, app: &App) {
-        if let Some(ref viewer) = app.foreign_key_viewer {
+        if let Some(viewer) = app.fk_navigation.current() {
             let area = centered_rect(80, 50, f.area());
             f.render_widget(Clear, area);
 
@@ -173,9 +166,13 @@ impl UI {
                         .iter()
                         .enumerate()
                         .map(|(col_idx, cell)| {
-                            let formatted = viewer.format_cell(cell);
+                            let formatted = viewer.format_cell(&cell.to_string());
                             let mut style = Style::default();
 
+                            if cell.is_null() {
+                                style = style.fg(Color::Gray).add_modifier(Modifier::ITALIC);
+                            }
+
                             if row_idx == view_data.current_row_relative
                                 && col_idx == view_data.current_col_relative
                             {
@@ -207,7 +204,7 @@ impl UI {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title("🔗 Foreign Key Lookup")
+                        .title(format!("🔗 {}", app.fk_navigation.breadcrumbs()))
                         .style(Style::default().fg(Color::Cyan)),
                 )
                 .column_spacing(1);
@@ -218,6 +215,68 @@ impl UI {
         }
     }
 
+    pub fn render_references_view(&self, f: &mut Frame, app: &App) {
+        if let Some(ref view) = app.references_view {
+            let area = centered_rect(70, 50, f.area());
+            f.render_widget(Clear, area);
+
+            let lines: Vec<Line> = view
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(idx, entry)| {
+                    let fk_info = &entry.foreign_key_info;
+                    let count = match &entry.row_count {
+                        Ok(1) => "1 row".to_string(),
+                        Ok(count) => format!("{} rows", count),
+                        Err(e) => format!("count failed: {}", e),
+                    };
+                    let style = if idx == view.selected {
+                        Style::default()
+                            .bg(Color::Blue)
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+
+                    Line::from(vec![
+                        Span::styled(
+                            format!(
+                                " {}.{}({}) ",
+                                fk_info.schema,
+                                fk_info.table_name,
+                                fk_info.columns.join(", ")
+                            ),
+                            style,
+                        ),
+                        Span::styled(format!(" {}", count), Style::default().fg(Color::Green)),
+                    ])
+                })
+                .collect();
+
+            let title = view
+                .entries
+                .first()
+                .map(|entry| {
+                    format!(
+                        "Rows referencing {}",
+                        entry.foreign_key_info.referenced_table
+                    )
+                })
+                .unwrap_or_default();
+
+            let list = Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .style(Style::default().fg(Color::Cyan)),
+            );
+
+            f.render_widget(list, area);
+        }
+    }
+
     fn render_foreign_key_status(
         &self,
         f: &mut Frame,
@@ -228,7 +287,7 @@ impl UI {
         let current_cell_value =
             if let Some(row) = view_data.rows.get(view_data.current_row_relative) {
                 if let Some(cell) = row.get(view_data.current_col_relative) {
-                    viewer.format_cell(cell)
+                    viewer.format_cell(&cell.to_string())
                 } else {
                     "N/A".to_string()
                 }
@@ -243,7 +302,7 @@ impl UI {
         };
 
         let status_text = format!(
-            "FK View | Rows: {}/{} | Cell: ({},{}):{} | Value: {} | y: Yank | Esc/q: Close",
+            "FK View | Rows: {}/{} | Cell: ({},{}):{} | Value: {} | F/R: Follow | [/]: Back/Fwd | Esc/q: Close",
             viewer.current_row + 1,
             view_data.total_rows,
             viewer.current_row + 1,
@@ -1,11 +1,12 @@
 use crate::{
     application::{app::App, state::AppMode},
-    ui::UI,
+    ui::{UI, profiles::profile_color},
 };
 
 use ratatui::{
     layout::{Alignment, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
@@ -13,23 +14,86 @@ use ratatui::{
 impl UI {
     pub fn render_footer(&self, f: &mut Frame, app: &App, area: Rect) {
         let footer_text = match app.current_mode {
-            AppMode::FileView => "e: Exec | ?: Help | q: Quit",
+            AppMode::FileView if app.is_querying => "Ctrl-C: Cancel query | ?: Help | q: Quit",
+            AppMode::FileView if app.in_transaction() => {
+                "e: Exec | x: Explain | C: Commit | X: Rollback | S: Schema | ?: Help | q: Quit"
+            }
+            AppMode::FileView => {
+                "e: Exec | x: Explain | B: Begin | S: Schema | P: Profiles | L: Listen | ?: Help | q: Quit"
+            }
             AppMode::TableViewer => {
-                "hjkl: Nav | c: Chart | K: Cell Info | F: FK Lookup | /: Search | ?: Help | q: Back"
+                "hjkl: Nav | c: Chart | K: Cell Info | F: FK Lookup | /: Search | R: Refs | M: More | ?: Help | q: Back"
+            }
+            AppMode::ForeignKeyView => {
+                "hjkl: Nav | F: Follow FK | R: Refs | [/Backspace: Back | ]: Forward | Esc/q: Close"
             }
-            AppMode::ForeignKeyView => "hjkl: Nav | Esc/q: Close",
             AppMode::CellInfoView => "F: View FK Data | Esc/q: Close | ?: Help",
+            AppMode::ReferencesView => "j/k: Select | Enter: Open rows | Esc/q: Close",
+            AppMode::SchemaBrowser if app.is_querying => "Ctrl-C: Cancel query | q: Back",
+            AppMode::SchemaBrowser => {
+                "j/k: Move | l/h: Expand/Collapse | Space: Toggle | Enter/p: Preview | r: Reload | q: Back"
+            }
             AppMode::MatrixLoading => "q: Quit",
             AppMode::Help => "?/Esc: Close",
             AppMode::Searching => "Searching...",
+            AppMode::ParameterPrompt => {
+                "Enter: Run | Tab: Next field | Ctrl-U: Clear | Esc: Cancel"
+            }
+            AppMode::ConfirmQuit => "c: Commit and quit | r: Roll back and quit | Esc: Cancel",
+            AppMode::ProfilePicker => "Enter: Connect | j/k: Move | Esc: Cancel",
+            AppMode::PlanViewer => {
+                "j/k: Move | l/h: Expand/Collapse | Space: Toggle | n: Next expensive | q: Back"
+            }
+            AppMode::ChannelMonitor
+                if app
+                    .channel_monitor
+                    .as_ref()
+                    .is_some_and(|monitor| monitor.input.is_some()) =>
+            {
+                "Enter: Listen | Ctrl-U: Clear | Esc: Cancel"
+            }
+            AppMode::ChannelMonitor => {
+                "j/k: Move | G: Follow | a: Channels | c: Clear | y: Yank payload | s: Stop | q: Back"
+            }
         };
 
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Color::Gray));
+        if app.config.database.read_only {
+            block = block.title(Span::styled(
+                " READ-ONLY ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if app.in_transaction() {
+            block = block.title(Span::styled(
+                " IN TRANSACTION ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(name) = &app.config.active_profile
+            && let Some(profile) = app.config.active_connection()
+        {
+            block = block.title_top(
+                Line::from(Span::styled(
+                    format!(" {} ", name),
+                    Style::default()
+                        .fg(profile_color(profile))
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .right_aligned(),
+            );
+        }
+
         let footer = Paragraph::new(footer_text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .style(Style::default().fg(Color::Gray)),
-            )
+            .block(block)
             .alignment(Alignment::Center);
 
         f.render_widget(footer, area);
@@ -27,7 +27,15 @@ impl UI {
                 "File View Mode:",
                 Style::default().fg(Color::Yellow),
             )),
-            Line::from("  e       - Execute query under cursor"),
+            Line::from("  e       - Execute query under cursor (prompts for parameters)"),
+            Line::from("  x       - Show the query plan for the query under cursor"),
+            Line::from("  A       - EXPLAIN ANALYZE the query under cursor (rolled back)"),
+            Line::from("  S       - Browse schemas, tables and columns"),
+            Line::from("  P       - Switch connection profile"),
+            Line::from("  L       - Watch LISTEN/NOTIFY channels"),
+            Line::from("  B       - Begin a transaction"),
+            Line::from("  C/X     - Commit/roll back the open transaction"),
+            Line::from("  Ctrl-C  - Cancel the running query"),
             Line::from("  j/k     - Navigate up/down"),
             Line::from("  q/Esc   - Quit"),
             Line::from(""),
@@ -39,7 +47,10 @@ impl UI {
             Line::from("  c       - Toggle column statistics chart"),
             Line::from("  K       - Show comprehensive cell information"),
             Line::from("  F       - Direct foreign key lookup"),
+            Line::from("  R       - List rows referencing the current row"),
             Line::from("  /       - Search table"),
+            Line::from("  M       - Fetch more rows of a truncated result"),
+            Line::from("  m       - Show or hide server messages"),
             Line::from("  yy      - Yank (copy) entire row"),
             Line::from("  yiw     - Yank current cell value"),
             Line::from("  q/Esc   - Return to file view"),
@@ -56,9 +67,54 @@ impl UI {
                 Style::default().fg(Color::Yellow),
             )),
             Line::from("  hjkl    - Navigate foreign key results"),
+            Line::from("  F       - Follow another foreign key from here"),
+            Line::from("  R       - List rows referencing the current row"),
+            Line::from("  [/Bksp  - Back to the previous lookup"),
+            Line::from("  ]       - Forward to the next lookup"),
             Line::from("  yy/yiw  - Yank row/cell from FK data"),
             Line::from("  Esc/q   - Close FK view"),
             Line::from(""),
+            Line::from(Span::styled(
+                "References View:",
+                Style::default().fg(Color::Yellow),
+            )),
+            Line::from("  j/k     - Select referencing foreign key"),
+            Line::from("  Enter   - Open the referencing rows"),
+            Line::from("  Esc/q   - Close references view"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Schema Browser:",
+                Style::default().fg(Color::Yellow),
+            )),
+            Line::from("  j/k     - Move selection"),
+            Line::from("  l/h     - Expand/collapse schema or table"),
+            Line::from("  Space   - Toggle expansion"),
+            Line::from("  Enter/p - Preview the selected table"),
+            Line::from("  r       - Reload the schema"),
+            Line::from("  q/Esc   - Return to file view"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Query Plan:",
+                Style::default().fg(Color::Yellow),
+            )),
+            Line::from("  j/k     - Move selection"),
+            Line::from("  l/h     - Expand/collapse a node"),
+            Line::from("  Space   - Toggle expansion"),
+            Line::from("  n       - Jump to the next expensive node"),
+            Line::from("  q/Esc   - Return to file view"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Channel Monitor:",
+                Style::default().fg(Color::Yellow),
+            )),
+            Line::from("  j/k     - Move selection"),
+            Line::from("  G       - Jump to and follow the newest notification"),
+            Line::from("  a       - Change the channels listened on"),
+            Line::from("  c       - Clear received notifications"),
+            Line::from("  y       - Copy the selected payload"),
+            Line::from("  s       - Stop listening and close the monitor"),
+            Line::from("  q/Esc   - Return to file view, still listening"),
+            Line::from(""),
             Line::from(Span::styled(
                 "Press ? or Esc to close help",
                 Style::default().fg(Color::Green),
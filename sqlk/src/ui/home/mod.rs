@@ -105,7 +105,7 @@ impl UI {
                 Line::from(vec![
                     Span::raw("  "),
                     Span::styled(
-                        format!("{} EXECUTING", spinner_char),
+                        format!("{} EXECUTING (Ctrl-C to cancel)", spinner_char),
                         Style::default()
                             .fg(Color::Green)
                             .add_modifier(Modifier::BOLD | Modifier::SLOW_BLINK),
@@ -118,7 +118,7 @@ impl UI {
                 ])
             };
 
-            let details_text = vec![
+            let mut details_text = vec![
                 Line::from(Span::styled(
                     "Status:",
                     Style::default()
@@ -127,6 +127,23 @@ impl UI {
                 )),
                 status_line,
                 Line::from(""),
+            ];
+            if let Some(outcome) = &app.last_outcome {
+                details_text.extend([
+                    Line::from(Span::styled(
+                        "Last Result:",
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from(Span::styled(
+                        format!("  {}", outcome),
+                        Style::default().fg(Color::Green),
+                    )),
+                    Line::from(""),
+                ]);
+            }
+            details_text.extend([
                 Line::from(Span::styled(
                     "Lines:",
                     Style::default()
@@ -150,7 +167,7 @@ impl UI {
                         .collect::<Vec<_>>()
                         .join("\n"),
                 ),
-            ];
+            ]);
 
             let paragraph = Paragraph::new(details_text)
                 .block(details_block)
@@ -161,7 +178,10 @@ impl UI {
             let help_text = if app.is_querying {
                 let spinner_char =
                     ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'][self.spinner_frame % 10];
-                format!("\n\n{} Executing query...\n\nPlease wait...", spinner_char)
+                format!(
+                    "\n\n{} Executing query...\n\nPress Ctrl-C to cancel.",
+                    spinner_char
+                )
             } else {
                 "\n\nMove cursor over a SQL query\nto see details here.".to_string()
             };
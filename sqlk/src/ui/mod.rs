@@ -1,13 +1,19 @@
+mod browser;
 mod cell;
+mod channels;
 mod chart;
 mod fk;
 mod footer;
 mod help;
 mod home;
 mod matrix;
+mod params;
+mod plan;
+mod profiles;
 mod search;
 mod table;
 mod toast;
+mod transaction;
 
 pub use toast::*;
 
@@ -56,11 +62,22 @@ impl UI {
         self.update_toasts(f.area());
 
         match app.current_mode {
-            AppMode::FileView => self.render_file_view(f, app),
-            AppMode::TableViewer | AppMode::ForeignKeyView | AppMode::CellInfoView => {
+            AppMode::FileView
+            | AppMode::ParameterPrompt
+            | AppMode::ConfirmQuit
+            | AppMode::ProfilePicker => {
+                self.render_file_view(f, app)
+            }
+            AppMode::TableViewer
+            | AppMode::ForeignKeyView
+            | AppMode::CellInfoView
+            | AppMode::ReferencesView => {
                 self.render_table_viewer(f, app)
             }
             AppMode::MatrixLoading => self.render_matrix_loading(f, app),
+            AppMode::SchemaBrowser => self.render_schema_browser(f, app),
+            AppMode::PlanViewer => self.render_plan_viewer(f, app),
+            AppMode::ChannelMonitor => self.render_channel_monitor(f, app),
             AppMode::Help => self.render_help(f, app),
             AppMode::Searching => self.render_search_input(f, app),
         }
@@ -69,8 +86,16 @@ impl UI {
             self.render_foreign_key_viewer(f, app);
         } else if app.current_mode == AppMode::CellInfoView {
             self.render_cell_info_viewer(f, app);
+        } else if app.current_mode == AppMode::ReferencesView {
+            self.render_references_view(f, app);
         } else if app.current_mode == AppMode::Help {
             self.render_help(f, app);
+        } else if app.current_mode == AppMode::ParameterPrompt {
+            self.render_parameter_prompt(f, app);
+        } else if app.current_mode == AppMode::ConfirmQuit {
+            self.render_quit_confirmation(f, app);
+        } else if app.current_mode == AppMode::ProfilePicker {
+            self.render_profile_picker(f, app);
         }
 
         if app.current_mode == AppMode::TableViewer
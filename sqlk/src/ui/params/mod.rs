@@ -0,0 +1,87 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Position},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::{
+    application::app::App,
+    ui::{UI, centered_rect},
+};
+
+impl UI {
+    pub fn render_parameter_prompt(&self, f: &mut Frame, app: &App) {
+        let Some(prompt) = &app.parameter_prompt else {
+            return;
+        };
+        let area = centered_rect(60, 50, f.area());
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Query Parameters")
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(2)])
+            .margin(1)
+            .split(inner);
+
+        let label_width = prompt
+            .names
+            .iter()
+            .map(|name| name.len())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<Line> = prompt
+            .names
+            .iter()
+            .zip(&prompt.values)
+            .enumerate()
+            .map(|(idx, (name, value))| {
+                let selected = idx == prompt.selected;
+                let label_style = if selected {
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>width$} = ", name, width = label_width),
+                        label_style,
+                    ),
+                    Span::styled(value.clone(), Style::default().fg(Color::White)),
+                ])
+            })
+            .collect();
+        f.render_widget(Paragraph::new(lines), chunks[0]);
+
+        let instructions = Paragraph::new(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green)),
+            Span::raw(": Run | "),
+            Span::styled("Tab/↑↓", Style::default().fg(Color::Cyan)),
+            Span::raw(": Next field | "),
+            Span::styled("NULL", Style::default().fg(Color::Cyan)),
+            Span::raw(": SQL NULL | "),
+            Span::styled("Esc", Style::default().fg(Color::Red)),
+            Span::raw(": Cancel"),
+        ]))
+        .style(Style::default().fg(Color::Gray));
+        f.render_widget(instructions, chunks[1]);
+
+        if (prompt.selected as u16) < chunks[0].height {
+            let value_width = prompt.values[prompt.selected].chars().count() as u16;
+            f.set_cursor_position(Position {
+                x: chunks[0].x + label_width as u16 + 3 + value_width,
+                y: chunks[0].y + prompt.selected as u16,
+            });
+        }
+    }
+}
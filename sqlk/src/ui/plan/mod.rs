@@ -0,0 +1,198 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+use crate::{
+    application::app::App,
+    query_plan::{PlanHeat, PlanNode, PlanViewer},
+    ui::UI,
+};
+
+/// Row estimates off by at least this factor are flagged.
+const MISESTIMATE_FACTOR: f64 = 10.0;
+
+impl UI {
+    pub fn render_plan_viewer(&self, f: &mut Frame, app: &App) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(9),
+                Constraint::Length(3),
+            ])
+            .split(f.area());
+
+        let Some(viewer) = &app.plan_viewer else {
+            self.render_footer(f, app, chunks[3]);
+            return;
+        };
+        let plan = &viewer.plan;
+
+        let mut header = vec![Span::styled(
+            plan.mode.label(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if let Some(planning) = plan.planning_time {
+            header.push(Span::raw(format!("  planning {:.3} ms", planning)));
+        }
+        if let Some(execution) = plan.execution_time {
+            header.push(Span::raw(format!("  execution {:.3} ms", execution)));
+        }
+        let query = plan.query.split_whitespace().collect::<Vec<_>>().join(" ");
+        header.push(Span::styled(
+            format!("  {}", query),
+            Style::default().fg(Color::DarkGray),
+        ));
+        let header = Paragraph::new(Line::from(header)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Query Plan")
+                .style(Style::default().fg(Color::Cyan)),
+        );
+        f.render_widget(header, chunks[0]);
+
+        self.render_plan_tree(f, viewer, chunks[1]);
+        self.render_plan_details(f, viewer, chunks[2]);
+        self.render_footer(f, app, chunks[3]);
+    }
+
+    fn render_plan_tree(&self, f: &mut Frame, viewer: &PlanViewer, area: Rect) {
+        let nodes = viewer.visible_nodes();
+        let height = area.height.saturating_sub(2) as usize;
+        let start = (viewer.selected + 1).saturating_sub(height);
+
+        let lines: Vec<Line> = nodes
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(height)
+            .map(|(position, &idx)| {
+                let mut line = plan_node_line(viewer, idx);
+                if position == viewer.selected {
+                    line = line.style(Style::default().bg(Color::DarkGray));
+                }
+                line
+            })
+            .collect();
+
+        let tree = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::White)),
+        );
+        f.render_widget(tree, area);
+    }
+
+    fn render_plan_details(&self, f: &mut Frame, viewer: &PlanViewer, area: Rect) {
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut lines = Vec::new();
+        if let Some(node) = viewer.selected_node() {
+            if let Some(buffers) = &node.buffers {
+                lines.push(Line::from(vec![
+                    Span::styled("Buffers: ", dim),
+                    Span::raw(buffers.summary()),
+                ]));
+            }
+            for (key, value) in &node.properties {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{}: ", key), dim),
+                    Span::raw(value.clone()),
+                ]));
+            }
+        }
+        for trigger in &viewer.plan.triggers {
+            lines.push(Line::from(vec![
+                Span::styled("Trigger ", dim),
+                Span::raw(trigger.clone()),
+            ]));
+        }
+
+        let details = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Node Details")
+                .style(Style::default().fg(Color::White)),
+        );
+        f.render_widget(details, area);
+    }
+}
+
+fn plan_node_line(viewer: &PlanViewer, idx: usize) -> Line<'static> {
+    let node = &viewer.plan.nodes[idx];
+    let dim = Style::default().fg(Color::DarkGray);
+    let marker = if node.descendants == 0 {
+        "  "
+    } else if viewer.is_collapsed(idx) {
+        "▸ "
+    } else {
+        "▾ "
+    };
+
+    let mut spans = vec![Span::raw(format!("{}{}", "  ".repeat(node.depth), marker))];
+    if let Some(subplan) = &node.subplan {
+        spans.push(Span::styled(format!("{}: ", subplan), dim));
+    }
+    spans.push(Span::styled(node.label.clone(), heat_style(node)));
+    spans.push(Span::styled(
+        format!("  cost={:.2}..{:.2}", node.startup_cost, node.total_cost),
+        dim,
+    ));
+
+    match &node.actual {
+        Some(actual) if actual.loops == 0.0 => {
+            spans.push(Span::styled("  never executed", dim));
+        }
+        Some(actual) => {
+            spans.push(Span::raw(format!(
+                "  time={:.3}..{:.3} ms",
+                actual.startup_time, actual.total_time
+            )));
+            let rows_style = if node
+                .misestimate()
+                .is_some_and(|factor| factor >= MISESTIMATE_FACTOR)
+            {
+                Style::default().fg(Color::Magenta)
+            } else {
+                Style::default()
+            };
+            spans.push(Span::styled(
+                format!("  rows={} est / {} actual", node.plan_rows, actual.rows),
+                rows_style,
+            ));
+            if actual.loops > 1.0 {
+                spans.push(Span::styled(format!("  loops={}", actual.loops), dim));
+            }
+        }
+        None => spans.push(Span::raw(format!("  rows={}", node.plan_rows))),
+    }
+    if let Some(buffers) = &node.buffers {
+        spans.push(Span::styled(
+            format!("  buffers: {}", buffers.summary()),
+            dim,
+        ));
+    }
+    if node.heat() != PlanHeat::Normal {
+        spans.push(Span::styled(
+            format!("  {:.0}%", node.share * 100.0),
+            heat_style(node),
+        ));
+    }
+    Line::from(spans).alignment(Alignment::Left)
+}
+
+fn heat_style(node: &PlanNode) -> Style {
+    match node.heat() {
+        PlanHeat::Hot => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        PlanHeat::Warm => Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+        PlanHeat::Normal => Style::default().add_modifier(Modifier::BOLD),
+    }
+}
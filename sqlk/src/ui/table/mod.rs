@@ -1,21 +1,44 @@
-use crate::{application::app::App, ui::UI};
+use crate::{
+    application::app::App,
+    database::{NoticeSeverity, ServerNotice},
+    ui::UI,
+};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
+/// Tallest the messages panel grows below a grid, borders included.
+const MAX_MESSAGES_HEIGHT: u16 = 8;
+
 impl UI {
     pub fn render_table_viewer(&self, f: &mut Frame, app: &App) {
         if let Some(ref viewer) = app.table_viewer {
+            let grid_height = f.area().height - 2;
+            // A statement without a result set only has its messages to show.
+            let messages_height = if viewer.data.headers.is_empty() {
+                grid_height
+            } else if viewer.show_messages {
+                (viewer.data.notices.len() as u16 + 2)
+                    .min(MAX_MESSAGES_HEIGHT)
+                    .min(grid_height / 2)
+            } else {
+                0
+            };
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([Constraint::Min(0), Constraint::Length(2)])
+                .constraints([
+                    Constraint::Min(0),
+                    Constraint::Length(messages_height),
+                    Constraint::Length(2),
+                ])
                 .split(f.area());
 
-            let view_data = viewer.get_visible_data(f.area().width, f.area().height - 2);
+            let view_data = viewer.get_visible_data(f.area().width, grid_height - messages_height);
 
             let headers: Vec<Cell> = view_data
                 .headers
@@ -45,9 +68,13 @@ impl UI {
                         .iter()
                         .enumerate()
                         .map(|(col_idx, cell)| {
-                            let formatted = viewer.format_cell(cell);
+                            let formatted = viewer.format_cell(&cell.to_string());
                             let mut style = Style::default();
 
+                            if cell.is_null() {
+                                style = style.fg(Color::DarkGray).add_modifier(Modifier::ITALIC);
+                            }
+
                             if view_data.show_chart {
                                 style = style.add_modifier(Modifier::DIM);
                             } else if row_idx == view_data.current_row_relative
@@ -88,14 +115,45 @@ impl UI {
                 .column_spacing(1);
 
             f.render_widget(table, chunks[0]);
-            self.render_table_status(f, app, viewer, &view_data, chunks[1]);
+            if messages_height > 0 {
+                self.render_messages(f, &viewer.data.notices, chunks[1]);
+            }
+            self.render_table_status(f, app, viewer, &view_data, chunks[2]);
         }
     }
 
+    fn render_messages(&self, f: &mut Frame, notices: &[ServerNotice], area: Rect) {
+        let lines: Vec<Line> = notices
+            .iter()
+            .map(|notice| {
+                let color = match notice.severity {
+                    NoticeSeverity::Warning => Color::Yellow,
+                    NoticeSeverity::Notice => Color::Cyan,
+                    NoticeSeverity::Info | NoticeSeverity::Debug => Color::DarkGray,
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{}: ", notice.severity.label()),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(notice.message.clone()),
+                ])
+            })
+            .collect();
+
+        let messages = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Messages ({})", notices.len()))
+                .style(Style::default().fg(Color::White)),
+        );
+        f.render_widget(messages, area);
+    }
+
     fn render_table_status(
         &self,
         f: &mut Frame,
-        _app: &App,
+        app: &App,
         viewer: &crate::table_viewer::TableViewer,
         view_data: &crate::table_viewer::TableViewData,
         area: Rect,
@@ -106,16 +164,47 @@ impl UI {
             ""
         };
 
-        let time_info = if let Some(duration) = view_data.execution_time {
-            format!(" | Time: {}ms", duration.as_millis())
+        let row_info = if viewer.is_truncated() && viewer.data.outcome.is_some() {
+            format!("{}+ (truncated)", view_data.total_rows)
+        } else if viewer.is_truncated() {
+            format!("{}+ (truncated, M: More)", view_data.total_rows)
         } else {
-            String::new()
+            view_data.total_rows.to_string()
+        };
+
+        let transaction_info = if app.in_transaction() {
+            "IN TRANSACTION | "
+        } else {
+            ""
+        };
+
+        let messages_info = match viewer.data.notices.len() {
+            0 => String::new(),
+            1 => "1 message (m) | ".to_string(),
+            count => format!("{} messages (m) | ", count),
+        };
+
+        let outcome_info = viewer
+            .data
+            .outcome_summary()
+            .map(|summary| format!("{} | ", summary))
+            .unwrap_or_default();
+
+        // The outcome summary already carries the timing.
+        let time_info = match view_data.execution_time {
+            Some(duration) if outcome_info.is_empty() => {
+                format!(" | Time: {}ms", duration.as_millis())
+            }
+            _ => String::new(),
         };
 
         let status_text = format!(
-            "Rows: {}/{} | Cell: ({},{}):{} | Cols: {}-{}/{} | FK: K | Help: ? | Chart: c | Quit: q{}",
+            "{}{}{}Rows: {}/{} | Cell: ({},{}):{} | Cols: {}-{}/{} | FK: K | Help: ? | Chart: c | Quit: q{}",
+            transaction_info,
+            outcome_info,
+            messages_info,
             viewer.current_row + 1,
-            view_data.total_rows,
+            row_info,
             viewer.current_row + 1,
             viewer.current_col + 1,
             fk_indicator,
@@ -0,0 +1,46 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::{
+    application::app::App,
+    ui::{UI, centered_rect},
+};
+
+impl UI {
+    pub fn render_quit_confirmation(&self, f: &mut Frame, _app: &App) {
+        let area = centered_rect(50, 20, f.area());
+        f.render_widget(Clear, area);
+
+        let key = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+        let text = vec![
+            Line::from(""),
+            Line::from("A transaction is still open."),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("c", key),
+                Span::raw(": Commit and quit | "),
+                Span::styled("r", key),
+                Span::raw(": Roll back and quit | "),
+                Span::styled("Esc", key),
+                Span::raw(": Cancel"),
+            ]),
+        ];
+
+        let popup = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Quit")
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .alignment(Alignment::Center);
+        f.render_widget(popup, area);
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use sqlk::config::Config;
+use sqlk::database::mock::{MockDatabaseClient, outcome_result, rows_result};
+use sqlk::database::{ColumnRef, DatabaseManager, ForeignKeyInfo, Value};
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.database = config.database.with_row_limit(2);
+    config
+}
+
+fn orders_customer_fk() -> ForeignKeyInfo {
+    ForeignKeyInfo {
+        constraint_name: "orders_customer_id_fkey".to_string(),
+        schema: "public".to_string(),
+        table_name: "orders".to_string(),
+        columns: vec!["customer_id".to_string()],
+        referenced_schema: "public".to_string(),
+        referenced_table: "customers".to_string(),
+        referenced_columns: vec!["id".to_string()],
+    }
+}
+
+#[tokio::test]
+async fn scripted_results_are_truncated_to_the_row_limit() {
+    let rows = (1..=3).map(|id| vec![Value::Int(id)]).collect();
+    let mock = Arc::new(
+        MockDatabaseClient::new().with_result("SELECT id FROM t", rows_result(&["id"], rows)),
+    );
+    let manager = DatabaseManager::from_client(mock.clone(), &config());
+
+    let result = manager.execute_query("SELECT id FROM t;").await.unwrap();
+
+    assert_eq!(result.headers, vec!["id"]);
+    assert_eq!(result.rows.len(), 2);
+    assert!(result.truncated);
+    assert_eq!(mock.executed_queries()[0].query, "SELECT id FROM t;");
+}
+
+#[tokio::test]
+async fn scripted_errors_and_unknown_queries_fail() {
+    let mock = MockDatabaseClient::new().with_error("SELECT 1/0", "division by zero");
+    let manager = DatabaseManager::from_client(Arc::new(mock), &config());
+
+    let error = manager.execute_query("SELECT 1/0").await.unwrap_err();
+    assert_eq!(error.to_string(), "division by zero");
+    assert!(manager.execute_query("SELECT 2").await.is_err());
+}
+
+#[tokio::test]
+async fn transaction_state_follows_begin_and_commit() {
+    let mock = MockDatabaseClient::new().with_default_result(outcome_result("UPDATE", 1));
+    let manager = DatabaseManager::from_client(Arc::new(mock), &config());
+
+    manager.execute_query("BEGIN").await.unwrap();
+    assert!(manager.in_transaction());
+    let result = manager.execute_query("UPDATE t SET a = 1").await.unwrap();
+    assert_eq!(result.outcome_summary().as_deref(), Some("UPDATE 1"));
+    manager.execute_query("COMMIT").await.unwrap();
+    assert!(!manager.in_transaction());
+}
+
+#[tokio::test]
+async fn foreign_keys_resolve_against_scripted_rows() {
+    let customers = rows_result(
+        &["id", "name"],
+        vec![vec![Value::Int(7), Value::Text("Ada".to_string())]],
+    );
+    let mock = MockDatabaseClient::new()
+        .with_foreign_key(orders_customer_fk())
+        .with_rows_by_key("public", "customers", customers);
+    let manager = DatabaseManager::from_client(Arc::new(mock), &config());
+
+    let column = ColumnRef::new(Some("public"), "orders", "customer_id");
+    let fk_info = manager.get_foreign_key_info(&column).unwrap().clone();
+    let result = manager
+        .lookup_foreign_key(&fk_info, &[Value::Int(7)])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.rows,
+        vec![vec![Value::Int(7), Value::Text("Ada".to_string())]]
+    );
+    assert_eq!(
+        manager
+            .get_referencing_foreign_keys(Some("public"), "customers")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn read_only_blocks_writes_before_the_client() {
+    let mut config = config();
+    config.database = config.database.with_read_only(true);
+    let mock = Arc::new(MockDatabaseClient::new().with_default_result(outcome_result("DELETE", 0)));
+    let manager = DatabaseManager::from_client(mock.clone(), &config);
+
+    let error = manager.execute_query("DELETE FROM t").await.unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Blocked on a read-only connection")
+    );
+    assert!(mock.executed_queries().is_empty());
+}